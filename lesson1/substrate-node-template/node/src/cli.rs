@@ -38,4 +38,8 @@ pub enum Subcommand {
 	/// The custom benchmark subcommmand benchmarking runtime pallets.
 	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Hash a file and look up or build its proof-of-existence claim.
+	#[structopt(name = "poe")]
+	Poe(crate::poe::PoeCmd),
 }
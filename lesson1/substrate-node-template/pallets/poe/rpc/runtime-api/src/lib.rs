@@ -0,0 +1,22 @@
+//! Runtime API definition for the poe pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait PoeApi<AccountId, Hash> where
+		AccountId: Codec,
+		Hash: Codec,
+	{
+		/// The proofs owned by `account`, one page at a time starting from page 0.
+		fn claims_of(account: AccountId, page: u32) -> Vec<Vec<u8>>;
+		/// Whether `leaf` is part of the anchored batch with the given `root`.
+		fn verify_inclusion(root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool;
+		/// The notaries which attested `proof`.
+		fn attestations_of(proof: Vec<u8>) -> Vec<AccountId>;
+		/// Whether `proof` is attested by enough registered notaries.
+		fn is_verified(proof: Vec<u8>) -> bool;
+	}
+}
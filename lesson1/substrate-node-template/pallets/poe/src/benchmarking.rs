@@ -0,0 +1,580 @@
+// 存证模块的基准测试
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash};
+use sp_std::{vec, vec::Vec};
+
+use crate::Pallet as Poe;
+
+const SEED: u32 = 0;
+
+// 为账户充值，足以支付任意存证的押金
+fn funded<T: Config>(who: T::AccountId) -> T::AccountId {
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+    who
+}
+
+// 用于填充账户存证的第 i 个存证，与 vec![0; p] 不同
+fn filler(i: u32) -> Vec<u8> {
+    let mut proof = vec![1];
+    proof.extend_from_slice(&i.to_le_bytes());
+    proof
+}
+
+// n 个最大长度且互不相同的存证
+fn batch_of<T: Config>(n: u32) -> Vec<Vec<u8>> {
+    (0 .. n).map(|i| {
+        let mut proof = vec![0; T::StringLimit::get() as usize];
+        proof[.. 4].copy_from_slice(&i.to_le_bytes());
+        proof
+    }).collect()
+}
+
+// 当前区块创建的存证所能设置的最晚过期时间
+fn max_expiry<T: Config>() -> T::BlockNumber {
+    <frame_system::Module<T>>::block_number() + T::MaxClaimDuration::get()
+}
+
+// 所有字段都为最大长度的元数据
+fn max_metadata<T: Config>() -> ClaimMetadata {
+    ClaimMetadata {
+        title: vec![0; T::MaxTitleLength::get() as usize],
+        mime_type: vec![0; T::MaxMimeTypeLength::get() as usize],
+        uri: vec![0; T::MaxUriLength::get() as usize],
+        tags: vec![vec![0; T::MaxTagLength::get() as usize]; T::MaxTags::get() as usize],
+    }
+}
+
+// max_metadata 所有字段的总长度
+fn metadata_bytes<T: Config>() -> u32 {
+    T::MaxTitleLength::get() + T::MaxMimeTypeLength::get() + T::MaxUriLength::get()
+        + T::MaxTags::get() * T::MaxTagLength::get()
+}
+
+// 共 m 个字节的元数据，按顺序填满 max_metadata 的各个字段
+fn metadata_of<T: Config>(m: u32) -> ClaimMetadata {
+    let mut left = m;
+    let mut take = |limit: u32| {
+        let len = left.min(limit);
+        left -= len;
+        vec![0; len as usize]
+    };
+    let title = take(T::MaxTitleLength::get());
+    let mime_type = take(T::MaxMimeTypeLength::get());
+    let uri = take(T::MaxUriLength::get());
+    let tags = (0 .. T::MaxTags::get())
+        .map(|_| take(T::MaxTagLength::get()))
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    ClaimMetadata { title, mime_type, uri, tags }
+}
+
+// 填充 who 的存证，直到只能再创建 room 个存证
+// 其中的存证尽量在 expiry 过期，只给调用方再留一个位置，使过期列表尽可能长
+fn fill_claims<T: Config>(
+    who: &T::AccountId,
+    room: u32,
+    expiry: Option<T::BlockNumber>,
+) -> Result<(), &'static str> {
+    for i in 0 .. T::MaxClaimsPerAccount::get().saturating_sub(room) {
+        let expiry = expiry.filter(|_| i + 1 < T::MaxExpiriesPerBlock::get());
+        Poe::<T>::create_claim(RawOrigin::Signed(who.clone()).into(), filler(i), expiry, None)?;
+    }
+    Ok(())
+}
+
+// 将 who 的存证在 who 与另一账户之间来回转移，直到所有权历史达到 MaxHistoryLength，下一次转移需要丢弃创建记录之后最早的记录
+fn fill_history<T: Config>(who: &T::AccountId, proof: &[u8]) -> Result<(), &'static str> {
+    let peer = funded::<T>(account("peer", 0, SEED));
+    Poe::<T>::approve_sender(RawOrigin::Signed(peer.clone()).into(), who.clone())?;
+    Poe::<T>::approve_sender(RawOrigin::Signed(who.clone()).into(), peer.clone())?;
+    for _ in 0 .. T::MaxHistoryLength::get() / 2 {
+        Poe::<T>::transfer_claim(RawOrigin::Signed(who.clone()).into(), proof.to_vec(), peer.clone())?;
+        Poe::<T>::transfer_claim(RawOrigin::Signed(peer.clone()).into(), proof.to_vec(), who.clone())?;
+    }
+    Ok(())
+}
+
+// 为 who 创建最坏情况下的存证：who 的存证已满，所有存证同时过期，并带有最大长度的元数据
+fn worst_claim<T: Config>(who: &T::AccountId, proof: &[u8]) -> Result<(), &'static str> {
+    let expiry = Some(max_expiry::<T>());
+    fill_claims::<T>(who, 1, expiry)?;
+    Poe::<T>::create_claim(
+        RawOrigin::Signed(who.clone()).into(),
+        proof.to_vec(),
+        expiry,
+        Some(max_metadata::<T>()),
+    )?;
+    Ok(())
+}
+
+benchmarks! {
+    // 存证长度 p 从 1 到 StringLimit，账户的存证已满并带有最大长度的元数据
+    create_claim {
+        let p in 1 .. T::StringLimit::get();
+        let m in 0 .. metadata_bytes::<T>();
+        let caller = funded::<T>(whitelisted_caller());
+        let expiry = Some(max_expiry::<T>());
+        fill_claims::<T>(&caller, 1, expiry)?;
+        let proof: Vec<u8> = vec![0; p as usize];
+    }: _(RawOrigin::Signed(caller.clone()), proof.clone(), expiry, Some(metadata_of::<T>(m)))
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().owner, caller);
+    }
+
+    // 批量创建 n 个存证
+    create_claims {
+        let n in 1 .. T::MaxBatchSize::get();
+        let caller = funded::<T>(whitelisted_caller());
+        fill_claims::<T>(&caller, n, None)?;
+        let proofs = batch_of::<T>(n);
+    }: _(RawOrigin::Signed(caller.clone()), proofs.clone())
+    verify {
+        assert!(proofs.iter().all(|proof| Proofs::<T>::get(proof).unwrap().owner == caller));
+    }
+
+    // 批量撤销 n 个存证
+    revoke_claims {
+        let n in 1 .. T::MaxBatchSize::get();
+        let caller = funded::<T>(whitelisted_caller());
+        fill_claims::<T>(&caller, n, None)?;
+        let proofs = batch_of::<T>(n);
+        Poe::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), proofs.clone())?;
+    }: _(RawOrigin::Signed(caller), proofs.clone())
+    verify {
+        assert!(proofs.iter().all(|proof| !Proofs::<T>::contains_key(proof)));
+    }
+
+    // 批量转移 n 个存证，接收方的存证只能再接收 n 个
+    transfer_claims {
+        let n in 1 .. T::MaxBatchSize::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let dist = funded::<T>(account("dist", 0, SEED));
+        fill_claims::<T>(&dist, n, None)?;
+        let proofs = batch_of::<T>(n);
+        Poe::<T>::create_claims(RawOrigin::Signed(caller.clone()).into(), proofs.clone())?;
+        Poe::<T>::approve_sender(RawOrigin::Signed(dist.clone()).into(), caller.clone())?;
+        let transfers: Vec<(Vec<u8>, T::AccountId)> =
+            proofs.iter().map(|proof| (proof.clone(), dist.clone())).collect();
+    }: _(RawOrigin::Signed(caller), transfers)
+    verify {
+        assert!(proofs.iter().all(|proof| Proofs::<T>::get(proof).unwrap().owner == dist));
+    }
+
+    // 签名由 BenchmarkSigner 生成，押金由交易发送方质押
+    create_claim_for {
+        let p in 1 .. T::StringLimit::get();
+        let relayer = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        let expiry = Some(max_expiry::<T>());
+        let deadline = <frame_system::Module<T>>::block_number();
+        let payload = Poe::<T>::delegated_claim_payload(&proof, expiry, 0, deadline);
+        let (owner, signature) = T::BenchmarkSigner::sign(&payload);
+        fill_claims::<T>(&funded::<T>(owner.clone()), 1, expiry)?;
+    }: _(RawOrigin::Signed(relayer.clone()), owner.clone(), proof.clone(), expiry, deadline, signature)
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().owner, owner);
+        assert_eq!(ClaimDepositors::<T>::get(&proof), Some(relayer));
+    }
+
+    // 共有人数量 o 从 1 到 MaxCoOwners
+    create_cosigned_claim {
+        let p in 1 .. T::StringLimit::get();
+        let o in 1 .. T::MaxCoOwners::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let expiry = Some(max_expiry::<T>());
+        fill_claims::<T>(&caller, 1, expiry)?;
+        let proof: Vec<u8> = vec![0; p as usize];
+        let co_owners: Vec<T::AccountId> = (1 .. o).map(|i| account("co_owner", i, SEED)).collect();
+    }: _(RawOrigin::Signed(caller.clone()), proof.clone(), co_owners, o, expiry)
+    verify {
+        assert_eq!(CoSignedClaims::<T>::get(&proof).unwrap().co_owners.len(), o as usize);
+    }
+
+    // 最后一个共有人批准后存证生效
+    approve_claim {
+        let o in 2 .. T::MaxCoOwners::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; T::StringLimit::get() as usize];
+        let co_owners: Vec<T::AccountId> = (1 .. o).map(|i| account("co_owner", i, SEED)).collect();
+        Poe::<T>::create_cosigned_claim(
+            RawOrigin::Signed(caller).into(), proof.clone(), co_owners.clone(), o, None
+        )?;
+        for co_owner in &co_owners[.. co_owners.len() - 1] {
+            Poe::<T>::approve_claim(RawOrigin::Signed(co_owner.clone()).into(), proof.clone())?;
+        }
+        let last = co_owners[co_owners.len() - 1].clone();
+    }: _(RawOrigin::Signed(last), proof.clone())
+    verify {
+        assert_eq!(CoSignedClaims::<T>::get(&proof).unwrap().status, ClaimStatus::Finalized);
+    }
+
+    // 撤销最坏情况下的存证
+    revoke_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        worst_claim::<T>(&caller, &proof)?;
+    }: _(RawOrigin::Signed(caller), proof.clone())
+    verify {
+        assert!(!Proofs::<T>::contains_key(&proof));
+    }
+
+    // 转移给另一个账户，接收方事先同意接收，接收方的存证只差一条记录，所有权历史已满
+    transfer_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let dist = funded::<T>(account("dist", 0, SEED));
+        fill_claims::<T>(&dist, 1, None)?;
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), None, None)?;
+        fill_history::<T>(&caller, &proof)?;
+        Poe::<T>::approve_sender(RawOrigin::Signed(dist.clone()).into(), caller.clone())?;
+    }: _(RawOrigin::Signed(caller), proof.clone(), dist.clone())
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().owner, dist);
+    }
+
+    offer_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let to: T::AccountId = account("to", 0, SEED);
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), None, None)?;
+    }: _(RawOrigin::Signed(caller), proof.clone(), to.clone())
+    verify {
+        assert_eq!(PendingTransfers::<T>::get(&proof), Some(to));
+    }
+
+    // 接收方的存证只差一条记录，所有权历史已满
+    accept_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let to = funded::<T>(account("to", 0, SEED));
+        fill_claims::<T>(&to, 1, None)?;
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), None, None)?;
+        fill_history::<T>(&caller, &proof)?;
+        Poe::<T>::offer_claim(RawOrigin::Signed(caller).into(), proof.clone(), to.clone())?;
+    }: _(RawOrigin::Signed(to.clone()), proof.clone())
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().owner, to);
+    }
+
+    cancel_offer {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let to: T::AccountId = account("to", 0, SEED);
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), None, None)?;
+        Poe::<T>::offer_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), to)?;
+    }: _(RawOrigin::Signed(caller), proof.clone())
+    verify {
+        assert!(!PendingTransfers::<T>::contains_key(&proof));
+    }
+
+    approve_sender {
+        let caller: T::AccountId = whitelisted_caller();
+        let sender: T::AccountId = account("sender", 0, SEED);
+    }: _(RawOrigin::Signed(caller.clone()), sender.clone())
+    verify {
+        assert!(TransferApprovals::<T>::get(&caller, &sender));
+    }
+
+    unapprove_sender {
+        let caller: T::AccountId = whitelisted_caller();
+        let sender: T::AccountId = account("sender", 0, SEED);
+        Poe::<T>::approve_sender(RawOrigin::Signed(caller.clone()).into(), sender.clone())?;
+    }: _(RawOrigin::Signed(caller.clone()), sender.clone())
+    verify {
+        assert!(!TransferApprovals::<T>::get(&caller, &sender));
+    }
+
+    // 存证从已满的过期列表移到新的过期区块
+    renew_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        worst_claim::<T>(&caller, &proof)?;
+        let next = <frame_system::Module<T>>::block_number() + 1u32.into();
+        <frame_system::Module<T>>::set_block_number(next);
+        let expiry = Some(max_expiry::<T>());
+    }: _(RawOrigin::Signed(caller), proof.clone(), expiry)
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().expiry, expiry);
+    }
+
+    // 清除原有的元数据后设置 m 个字节的元数据，补充质押其押金
+    set_claim_metadata {
+        let p in 1 .. T::StringLimit::get();
+        let m in 0 .. metadata_bytes::<T>();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        worst_claim::<T>(&caller, &proof)?;
+        Poe::<T>::set_claim_metadata(RawOrigin::Signed(caller.clone()).into(), proof.clone(), None)?;
+    }: _(RawOrigin::Signed(caller), proof.clone(), Some(metadata_of::<T>(m)))
+    verify {
+        assert_eq!(ClaimMetadataOf::<T>::get(&proof), Some(metadata_of::<T>(m)));
+    }
+
+    anchor_batch {
+        let caller = funded::<T>(whitelisted_caller());
+        let root = T::Hash::default();
+    }: _(RawOrigin::Signed(caller), root, u32::max_value())
+    verify {
+        assert!(Batches::<T>::contains_key(&root));
+    }
+
+    revoke_batch {
+        let caller = funded::<T>(whitelisted_caller());
+        let root = T::Hash::default();
+        Poe::<T>::anchor_batch(RawOrigin::Signed(caller.clone()).into(), root, u32::max_value())?;
+    }: _(RawOrigin::Signed(caller), root)
+    verify {
+        assert!(!Batches::<T>::contains_key(&root));
+    }
+
+    // Merkle 证明的深度 d 从 0 到 MAX_MERKLE_DEPTH
+    claim_from_batch {
+        let d in 0 .. MAX_MERKLE_DEPTH;
+        let caller = funded::<T>(whitelisted_caller());
+        fill_claims::<T>(&caller, 1, None)?;
+        let leaf = T::Hash::default();
+        let proof: Vec<T::Hash> = (0 .. d).map(|i| T::Hashing::hash_of(&i)).collect();
+        let root = proof.iter().fold(T::Hashing::hash_of(&(MERKLE_LEAF_PREFIX, leaf)), |node, sibling| {
+            if node <= *sibling {
+                T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, node, *sibling))
+            } else {
+                T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, *sibling, node))
+            }
+        });
+        let leaf_count = if d == MAX_MERKLE_DEPTH { u32::max_value() } else { 1 << d };
+        Poe::<T>::anchor_batch(RawOrigin::Signed(caller.clone()).into(), root, leaf_count)?;
+    }: _(RawOrigin::Signed(caller.clone()), root, leaf, proof)
+    verify {
+        assert_eq!(Proofs::<T>::get(leaf.as_ref()).unwrap().owner, caller);
+    }
+
+    commit_claim {
+        let caller = funded::<T>(whitelisted_caller());
+        let commitment = T::Hash::default();
+    }: _(RawOrigin::Signed(caller), commitment)
+    verify {
+        assert!(Commitments::<T>::contains_key(&commitment));
+    }
+
+    // 存证长度 p 和盐的长度 s 都计入承诺的哈希
+    reveal_claim {
+        let p in 1 .. T::StringLimit::get();
+        let s in 0 .. 1_024;
+        let caller = funded::<T>(whitelisted_caller());
+        fill_claims::<T>(&caller, 1, None)?;
+        let proof: Vec<u8> = vec![0; p as usize];
+        let salt: Vec<u8> = vec![0; s as usize];
+        let commitment = Poe::<T>::commitment_of(&proof, &salt, &caller);
+        Poe::<T>::commit_claim(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+    }: _(RawOrigin::Signed(caller.clone()), proof.clone(), salt)
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().owner, caller);
+    }
+
+    reap_commitment {
+        let caller = funded::<T>(whitelisted_caller());
+        let commitment = T::Hash::default();
+        Poe::<T>::commit_claim(RawOrigin::Signed(caller.clone()).into(), commitment)?;
+        let closed = <frame_system::Module<T>>::block_number() + T::RevealWindow::get() + 1u32.into();
+        <frame_system::Module<T>>::set_block_number(closed);
+    }: _(RawOrigin::Signed(caller), commitment)
+    verify {
+        assert!(!Commitments::<T>::contains_key(&commitment));
+    }
+
+    add_notary {
+        let notary: T::AccountId = account("notary", 0, SEED);
+    }: _(RawOrigin::Root, notary.clone())
+    verify {
+        assert!(Notaries::<T>::get(&notary));
+    }
+
+    remove_notary {
+        let notary: T::AccountId = account("notary", 0, SEED);
+        Poe::<T>::add_notary(RawOrigin::Root.into(), notary.clone())?;
+    }: _(RawOrigin::Root, notary.clone())
+    verify {
+        assert!(!Notaries::<T>::get(&notary));
+    }
+
+    // 除最后一个公证人外，所有公证人都已证明该存证
+    attest_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller).into(), proof.clone(), None, None)?;
+        let notaries: Vec<T::AccountId> =
+            (0 .. T::MaxNotaries::get()).map(|i| account("notary", i, SEED)).collect();
+        for notary in &notaries {
+            Poe::<T>::add_notary(RawOrigin::Root.into(), notary.clone())?;
+        }
+        for notary in &notaries[.. notaries.len() - 1] {
+            Poe::<T>::attest_claim(RawOrigin::Signed(notary.clone()).into(), proof.clone())?;
+        }
+        let last = notaries[notaries.len() - 1].clone();
+    }: _(RawOrigin::Signed(last), proof.clone())
+    verify {
+        assert_eq!(Attestations::<T>::get(&proof).len(), notaries.len());
+    }
+
+    challenge_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let challenger = funded::<T>(account("challenger", 0, SEED));
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller).into(), proof.clone(), None, None)?;
+    }: _(RawOrigin::Signed(challenger.clone()), proof.clone(), T::Hash::default())
+    verify {
+        assert_eq!(Disputes::<T>::get(&proof).unwrap().challenger, challenger);
+    }
+
+    // 存证重新分配给争议人，争议人的存证只差一条记录，所有权历史已满
+    resolve_dispute {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let challenger = funded::<T>(account("challenger", 0, SEED));
+        fill_claims::<T>(&challenger, 1, None)?;
+        let proof: Vec<u8> = vec![0; p as usize];
+        worst_claim::<T>(&caller, &proof)?;
+        fill_history::<T>(&caller, &proof)?;
+        Poe::<T>::challenge_claim(
+            RawOrigin::Signed(challenger.clone()).into(), proof.clone(), T::Hash::default()
+        )?;
+    }: _(RawOrigin::Root, proof.clone(), false)
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().owner, challenger);
+    }
+
+    force_revoke {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        worst_claim::<T>(&caller, &proof)?;
+        let origin = T::ForceOrigin::successful_origin();
+    }: _<T::Origin>(origin, proof.clone())
+    verify {
+        assert!(!Proofs::<T>::contains_key(&proof));
+    }
+
+    // 存证处于争议中，转移时退还争议人的保证金
+    force_transfer {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let dist = funded::<T>(account("dist", 0, SEED));
+        let challenger = funded::<T>(account("challenger", 0, SEED));
+        fill_claims::<T>(&dist, 1, None)?;
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller.clone()).into(), proof.clone(), None, None)?;
+        fill_history::<T>(&caller, &proof)?;
+        Poe::<T>::challenge_claim(RawOrigin::Signed(challenger).into(), proof.clone(), T::Hash::default())?;
+        let origin = T::ForceOrigin::successful_origin();
+    }: _<T::Origin>(origin, proof.clone(), dist.clone())
+    verify {
+        assert_eq!(Proofs::<T>::get(&proof).unwrap().owner, dist);
+        assert!(!Disputes::<T>::contains_key(&proof));
+    }
+
+    freeze_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller).into(), proof.clone(), None, None)?;
+        let origin = T::ForceOrigin::successful_origin();
+    }: _<T::Origin>(origin, proof.clone())
+    verify {
+        assert!(FrozenClaims::<T>::get(&proof));
+    }
+
+    thaw_claim {
+        let p in 1 .. T::StringLimit::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let proof: Vec<u8> = vec![0; p as usize];
+        Poe::<T>::create_claim(RawOrigin::Signed(caller).into(), proof.clone(), Some(max_expiry::<T>()), None)?;
+        Poe::<T>::freeze_claim(T::ForceOrigin::successful_origin(), proof.clone())?;
+        let origin = T::ForceOrigin::successful_origin();
+    }: _<T::Origin>(origin, proof.clone())
+    verify {
+        assert!(!FrozenClaims::<T>::get(&proof));
+    }
+
+    create_hashed_claim {
+        let caller = funded::<T>(whitelisted_caller());
+        let expiry = Some(max_expiry::<T>());
+        fill_claims::<T>(&caller, 1, expiry)?;
+        let digest = [0u8; 32];
+    }: _(RawOrigin::Signed(caller.clone()), HashAlgorithm::Blake2_256, digest, expiry)
+    verify {
+        assert_eq!(Proofs::<T>::get(HashAlgorithm::Blake2_256.tagged(&digest)).unwrap().owner, caller);
+    }
+
+    // 内容长度 c 从 0 到 MaxContentLength
+    hash_and_claim {
+        let c in 0 .. T::MaxContentLength::get();
+        let caller = funded::<T>(whitelisted_caller());
+        let expiry = Some(max_expiry::<T>());
+        fill_claims::<T>(&caller, 1, expiry)?;
+        let content: Vec<u8> = vec![0; c as usize];
+        let digest = HashAlgorithm::Sha2_256.hash(&content);
+    }: _(RawOrigin::Signed(caller.clone()), HashAlgorithm::Sha2_256, content, expiry)
+    verify {
+        assert_eq!(Proofs::<T>::get(HashAlgorithm::Sha2_256.tagged(&digest)).unwrap().owner, caller);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{new_test_ext, Test};
+    use frame_support::assert_ok;
+
+    // 在测试环境中执行每个基准测试
+    #[test]
+    fn test_benchmarks() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_create_claim::<Test>());
+            assert_ok!(test_benchmark_create_claims::<Test>());
+            assert_ok!(test_benchmark_revoke_claims::<Test>());
+            assert_ok!(test_benchmark_transfer_claims::<Test>());
+            assert_ok!(test_benchmark_create_claim_for::<Test>());
+            assert_ok!(test_benchmark_create_cosigned_claim::<Test>());
+            assert_ok!(test_benchmark_approve_claim::<Test>());
+            assert_ok!(test_benchmark_revoke_claim::<Test>());
+            assert_ok!(test_benchmark_transfer_claim::<Test>());
+            assert_ok!(test_benchmark_offer_claim::<Test>());
+            assert_ok!(test_benchmark_accept_claim::<Test>());
+            assert_ok!(test_benchmark_cancel_offer::<Test>());
+            assert_ok!(test_benchmark_approve_sender::<Test>());
+            assert_ok!(test_benchmark_unapprove_sender::<Test>());
+            assert_ok!(test_benchmark_renew_claim::<Test>());
+            assert_ok!(test_benchmark_set_claim_metadata::<Test>());
+            assert_ok!(test_benchmark_anchor_batch::<Test>());
+            assert_ok!(test_benchmark_revoke_batch::<Test>());
+            assert_ok!(test_benchmark_claim_from_batch::<Test>());
+            assert_ok!(test_benchmark_commit_claim::<Test>());
+            assert_ok!(test_benchmark_reveal_claim::<Test>());
+            assert_ok!(test_benchmark_reap_commitment::<Test>());
+            assert_ok!(test_benchmark_add_notary::<Test>());
+            assert_ok!(test_benchmark_remove_notary::<Test>());
+            assert_ok!(test_benchmark_attest_claim::<Test>());
+            assert_ok!(test_benchmark_challenge_claim::<Test>());
+            assert_ok!(test_benchmark_resolve_dispute::<Test>());
+            assert_ok!(test_benchmark_force_revoke::<Test>());
+            assert_ok!(test_benchmark_force_transfer::<Test>());
+            assert_ok!(test_benchmark_freeze_claim::<Test>());
+            assert_ok!(test_benchmark_thaw_claim::<Test>());
+            assert_ok!(test_benchmark_create_hashed_claim::<Test>());
+            assert_ok!(test_benchmark_hash_and_claim::<Test>());
+        });
+    }
+}
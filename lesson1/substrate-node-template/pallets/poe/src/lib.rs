@@ -9,26 +9,319 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+    use frame_support::{
+        dispatch::{DispatchResult, DispatchResultWithPostInfo}, pallet_prelude::*,
+        storage::{with_transaction, TransactionOutcome},
+        traits::{BalanceStatus, Currency, ReservableCurrency, UnixTime},
+    };
     use frame_system::pallet_prelude::*;
-    use sp_std::vec::Vec;
+    use sp_io::hashing::{blake2_256, keccak_256, sha2_256};
+    use sp_runtime::traits::{Hash as HashT, IdentifyAccount, One, Saturating, Verify, Zero};
+    use sp_std::{vec, vec::Vec};
+
+    use crate::weights::WeightInfo;
+
+    // claims_of 每页返回的存证数量
+    pub const CLAIMS_PAGE_SIZE: u32 = 20;
+
+    // 最多 u32::max_value() 个叶子的默克尔树深度
+    pub const MAX_MERKLE_DEPTH: u32 = 32;
+
+    // 文件哈希作为默克尔树叶子哈希前添加的前缀
+    pub const MERKLE_LEAF_PREFIX: u8 = 0;
+
+    // 两个默克尔树节点哈希为父节点前添加的前缀
+    pub const MERKLE_NODE_PREFIX: u8 = 1;
+
+    // 哈希存证的键的长度：1字节的算法标记加32字节的摘要
+    pub const HASHED_PROOF_LENGTH: u32 = 33;
+
+    // create_claim_for 签名内容的前缀，签名不能用于其他调用
+    pub const DELEGATED_CLAIM_CONTEXT: &[u8] = b"pallet-poe:create_claim_for";
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    // 链上保存的存证信息
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+        // 存证所有者
+        pub owner: AccountId,
+        // 创建（或最近一次转移）时的区块高度
+        pub block_number: BlockNumber,
+        // block_number 对应的 Unix 时间（毫秒）
+        pub moment: u64,
+        // 存证最初创建时的 Unix 时间（毫秒），转移后保持不变
+        pub created_at: u64,
+        // 为该存证及其描述信息质押的押金
+        pub deposit: Balance,
+        // 存证的过期区块，None 表示永久有效
+        pub expiry: Option<BlockNumber>,
+    }
+
+    // 存证附带的描述信息，长度受 Config 中对应的上限限制
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct ClaimMetadata {
+        // 文件标题
+        pub title: Vec<u8>,
+        // 文件的 MIME 类型，如 application/pdf
+        pub mime_type: Vec<u8>,
+        // 文件的外部地址，如 IPFS CID
+        pub uri: Vec<u8>,
+        // 自定义标签
+        pub tags: Vec<Vec<u8>>,
+    }
+
+    // 哈希存证使用的哈希算法
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum HashAlgorithm {
+        Blake2_256,
+        Sha2_256,
+        Keccak256,
+    }
+
+    impl HashAlgorithm {
+        // 计算 data 的32字节摘要
+        pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+            match self {
+                HashAlgorithm::Blake2_256 => blake2_256(data),
+                HashAlgorithm::Sha2_256 => sha2_256(data),
+                HashAlgorithm::Keccak256 => keccak_256(data),
+            }
+        }
+
+        // 以此算法计算的摘要 digest 的存证键：编码后的算法标记加摘要，不同算法的相同摘要互不冲突
+        pub fn tagged(&self, digest: &[u8; 32]) -> Vec<u8> {
+            (self, digest).encode()
+        }
+    }
+
+    // 多方共同签署存证的状态
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum ClaimStatus {
+        // 同意的共同所有者不足门限
+        Pending,
+        // 已有足够的共同所有者同意
+        Finalized,
+    }
+
+    // 多方共同签署存证的共同所有者及同意情况
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct CoSignInfo<AccountId> {
+        // 共同所有者，包括创建人
+        pub co_owners: Vec<AccountId>,
+        // 确认、销毁或转移存证所需的同意人数
+        pub threshold: u32,
+        // 已同意的共同所有者
+        pub approvals: Vec<AccountId>,
+        // 存证是否已确认
+        pub status: ClaimStatus,
+    }
+
+    // 多方共同签署存证上需要门限同意的操作
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub enum ClaimAction<AccountId, BlockNumber> {
+        // 销毁存证
+        Revoke,
+        // 转移存证给指定账户
+        Transfer(AccountId),
+        // 修改存证的过期区块
+        Renew(Option<BlockNumber>),
+    }
+
+    // 链上保存的批量存证（默克尔根）信息
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct BatchInfo<AccountId, BlockNumber, Balance> {
+        // 批量存证所有者
+        pub owner: AccountId,
+        // 锚定时的区块高度
+        pub block_number: BlockNumber,
+        // 锚定时的 Unix 时间（毫秒）
+        pub moment: u64,
+        // 批量中的文件数量
+        pub leaf_count: u32,
+        // 为该默克尔根质押的押金
+        pub deposit: Balance,
+    }
+
+    // 对存证所有权提出的争议
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct DisputeInfo<AccountId, Balance, Hash> {
+        // 提出争议的账户
+        pub challenger: AccountId,
+        // 争议证据的哈希
+        pub evidence: Hash,
+        // 争议解决之前质押的保证金
+        pub bond: Balance,
+    }
+
+    // 尚未揭示的加盐存证承诺
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct CommitmentInfo<AccountId, BlockNumber, Balance> {
+        // 承诺的所有者
+        pub owner: AccountId,
+        // 提交承诺时的区块高度，揭示后作为存证的区块高度
+        pub block_number: BlockNumber,
+        // 提交承诺时的 Unix 时间（毫秒）
+        pub moment: u64,
+        // 揭示或清理之前质押的押金
+        pub deposit: Balance,
+    }
+
+    // 存储结构的版本，用于判断需要执行哪些迁移
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum Releases {
+        // Proofs 保存 (所有者, 区块高度)
+        V1_0_0,
+        // Proofs 保存 ClaimInfo
+        V2_0_0,
+    }
+
+    impl Default for Releases {
+        fn default() -> Self {
+            Releases::V1_0_0
+        }
+    }
+
+    // 在基准测试中生成 create_claim_for 的签名
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkSigner<AccountId, Signature> {
+        // 新生成的账户及其对 payload 的签名
+        fn sign(payload: &[u8]) -> (AccountId, Signature);
+    }
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         type StringLimit: Get<u32>;
+        // 用于质押押金的货币
+        type Currency: ReservableCurrency<Self::AccountId>;
+        // 每个存证的基础押金
+        type ClaimDepositBase: Get<BalanceOf<Self>>;
+        // 存证及其编码后的描述信息每个字节额外收取的押金
+        type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+        // 每个存证最多记录的所有者历史条数，超出时保留创建记录并丢弃之后最早的记录
+        type MaxHistoryLength: Get<u32>;
+        // 每个账户最多拥有的存证数量
+        type MaxClaimsPerAccount: Get<u32>;
+        // 存证最长有效期（区块数）
+        type MaxClaimDuration: Get<Self::BlockNumber>;
+        // 每个区块最多过期的存证数量，到期时全部清理
+        type MaxExpiriesPerBlock: Get<u32>;
+        // hash_and_claim 链上哈希的内容最大长度
+        type MaxContentLength: Get<u32>;
+        // 多方共同签署存证的最大共同所有者数量
+        type MaxCoOwners: Get<u32>;
+        // 存证标题的最大长度
+        type MaxTitleLength: Get<u32>;
+        // 存证 MIME 类型的最大长度
+        type MaxMimeTypeLength: Get<u32>;
+        // 存证外部地址的最大长度
+        type MaxUriLength: Get<u32>;
+        // 存证标签的最大数量
+        type MaxTags: Get<u32>;
+        // 单个标签的最大长度
+        type MaxTagLength: Get<u32>;
+        // 存证记录的 Unix 时间的来源
+        type UnixTime: UnixTime;
+        // 预期的出块间隔（毫秒），用于估算记录时间之前创建的存证的时间
+        type ExpectedBlockTime: Get<u64>;
+        // 所有者授权 create_claim_for 使用的签名类型
+        type Signature: Verify<Signer = Self::Signer> + Parameter;
+        // 签名对应的公钥类型，可以转换为所有者的账户
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
+        // 提交承诺后可以揭示的区块数，超过后承诺可以被清理
+        type RevealWindow: Get<Self::BlockNumber>;
+        // 公证人数量上限
+        type MaxNotaries: Get<u32>;
+        // 存证被视为已验证所需的公证人证明数量
+        type AttestationThreshold: Get<u32>;
+        // 对存证提出争议需要质押的保证金
+        type ChallengeBond: Get<BalanceOf<Self>>;
+        // 可以强制销毁、转移、冻结和解冻存证的来源
+        type ForceOrigin: EnsureOrigin<Self::Origin>;
+        // 批量调用一次最多处理的存证数量
+        type MaxBatchSize: Get<u32>;
+        // 交易的权重信息
+        type WeightInfo: WeightInfo;
+        // 在基准测试中生成 create_claim_for 的签名
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkSigner: BenchmarkSigner<Self::AccountId, Self::Signature>;
     }
 
     #[pallet::event]
-    #[pallet::metadata(T::AccountId = "AccountId")]
+    #[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber", T::Hash = "Hash")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        // 存证创建时触发的事件. [创建人, 存证]
-        ClaimCreated(T::AccountId, Vec<u8>),
+        // 存证创建时触发的事件. [创建人, 存证, Unix 时间（毫秒）]
+        ClaimCreated(T::AccountId, Vec<u8>, u64),
         // 存证销毁时触发的事件. [创建人, 存证]
         ClaimRevoked(T::AccountId, Vec<u8>),
+        // 存证转移时触发的事件. [原所有者, 新所有者, 存证]
+        ClaimTransferred(T::AccountId, T::AccountId, Vec<u8>),
+        // 存证过期被清理时触发的事件. [所有者, 存证]
+        ClaimExpired(T::AccountId, Vec<u8>),
+        // 存证续期时触发的事件. [所有者, 存证, 新的过期区块]
+        ClaimRenewed(T::AccountId, Vec<u8>, Option<T::BlockNumber>),
+        // 默克尔根锚定时触发的事件. [所有者, 默克尔根, 文件数量]
+        BatchAnchored(T::AccountId, T::Hash, u32),
+        // 批量存证销毁时触发的事件. [所有者, 默克尔根]
+        BatchRevoked(T::AccountId, T::Hash),
+        // 发起存证转移时触发的事件. [所有者, 接收方, 存证]
+        TransferOffered(T::AccountId, T::AccountId, Vec<u8>),
+        // 取消存证转移时触发的事件. [所有者, 接收方, 存证]
+        TransferCancelled(T::AccountId, T::AccountId, Vec<u8>),
+        // 同意接收某账户的直接转移时触发的事件. [接收方, 发送方]
+        SenderApproved(T::AccountId, T::AccountId),
+        // 撤销同意时触发的事件. [接收方, 发送方]
+        SenderUnapproved(T::AccountId, T::AccountId),
+        // 共同所有者同意存证时触发的事件. [共同所有者, 存证]
+        ClaimApproved(T::AccountId, Vec<u8>),
+        // 多方共同签署存证达到门限时触发的事件. [存证]
+        ClaimFinalized(Vec<u8>),
+        // 共同所有者同意操作但尚未达到门限时触发的事件. [共同所有者, 存证, 操作]
+        ClaimActionApproved(T::AccountId, Vec<u8>, ClaimAction<T::AccountId, T::BlockNumber>),
+        // 存证描述信息更新时触发的事件. [所有者, 存证]
+        ClaimMetadataUpdated(T::AccountId, Vec<u8>),
+        // 提交存证承诺时触发的事件. [所有者, 承诺]
+        ClaimCommitted(T::AccountId, T::Hash),
+        // 揭示存证承诺时触发的事件. [所有者, 承诺, 存证]
+        ClaimRevealed(T::AccountId, T::Hash, Vec<u8>),
+        // 清理过期的存证承诺时触发的事件. [所有者, 承诺]
+        CommitmentReaped(T::AccountId, T::Hash),
+        // 添加公证人时触发的事件. [公证人]
+        NotaryAdded(T::AccountId),
+        // 移除公证人时触发的事件. [公证人]
+        NotaryRemoved(T::AccountId),
+        // 公证人证明存证时触发的事件. [公证人, 存证]
+        ClaimAttested(T::AccountId, Vec<u8>),
+        // 对存证提出争议时触发的事件. [争议人, 存证, 证据]
+        ClaimChallenged(T::AccountId, Vec<u8>, T::Hash),
+        // 争议裁定维持原所有者时触发的事件. [所有者, 争议人, 存证]
+        DisputeUpheld(T::AccountId, T::AccountId, Vec<u8>),
+        // 争议裁定将存证改判给争议人时触发的事件. [原所有者, 争议人, 存证]
+        ClaimReassigned(T::AccountId, T::AccountId, Vec<u8>),
+        // 争议裁定支持争议人但争议人无法接收存证，存证被移除时触发的事件. [原所有者, 争议人, 存证]
+        ClaimVoided(T::AccountId, T::AccountId, Vec<u8>),
+        // 强制销毁存证时触发的事件. [所有者, 存证]
+        ClaimForceRevoked(T::AccountId, Vec<u8>),
+        // 强制转移存证时触发的事件. [原所有者, 接收方, 存证]
+        ClaimForceTransferred(T::AccountId, T::AccountId, Vec<u8>),
+        // 冻结存证时触发的事件. [存证]
+        ClaimFrozen(Vec<u8>),
+        // 解冻存证时触发的事件. [存证]
+        ClaimThawed(Vec<u8>),
+        // 批量调用因某一项失败而回滚时触发的事件. [失败项的序号, 错误]
+        BatchInterrupted(u32, DispatchError),
     }
 
     #[pallet::error]
@@ -36,7 +329,87 @@ pub mod pallet {
         ProofAlreadyClaimed,
         NoSuchProof,
         NotProofOwner,
-        BadMetadata
+        BadMetadata,
+        // 余额不足以支付押金
+        InsufficientBalance,
+        // 账户拥有的存证数量已达上限
+        TooManyClaims,
+        // 过期区块已过去或超过最长有效期
+        InvalidExpiry,
+        // 该区块过期的存证数量已达上限
+        ExpiryBlockFull,
+        // 默克尔根已被锚定
+        BatchAlreadyAnchored,
+        // 默克尔根不存在
+        NoSuchBatch,
+        // 批量中至少需要一个文件
+        EmptyBatch,
+        // 包含证明无法推导出默克尔根
+        InvalidMerkleProof,
+        // 内容长度超过 MaxContentLength
+        ContentTooLong,
+        // 目的地址未同意接收发送人的直接转移
+        TransferNotApproved,
+        // 不能将存证转移给自己
+        TransferToSelf,
+        // 存证没有待接受的转移
+        NoPendingTransfer,
+        // 转移不是发给调用者的
+        NotTransferRecipient,
+        // 共同所有者数量超过 MaxCoOwners
+        TooManyCoOwners,
+        // 共同所有者重复
+        DuplicateCoOwner,
+        // 门限为0或大于共同所有者数量
+        InvalidThreshold,
+        // 调用者不是共同所有者
+        NotCoOwner,
+        // 调用者已经同意过
+        AlreadyApproved,
+        // 另一个操作正在等待共同所有者同意
+        ActionPending,
+        // 存证无需再被同意
+        AlreadyFinalized,
+        // 多方共同签署存证尚未确认
+        ClaimNotFinalized,
+        // 多方共同签署存证不支持该操作
+        CoSignedClaim,
+        // 签名与所有者或签名内容不符
+        InvalidSignature,
+        // 签名已过截止区块
+        SignatureExpired,
+        // 该承诺已经提交
+        CommitmentAlreadyExists,
+        // 没有与存证、盐值和发送方对应的承诺
+        NoSuchCommitment,
+        // 承诺已超过揭示期限
+        RevealWindowClosed,
+        // 承诺仍在揭示期限内，不能清理
+        RevealWindowOpen,
+        // 该账户已经是公证人
+        AlreadyNotary,
+        // 该账户不是公证人
+        NotNotary,
+        // 公证人数量已达上限
+        TooManyNotaries,
+        // 公证人已经证明过该存证
+        AlreadyAttested,
+        // 存证存在争议，争议解决前不能转移或销毁
+        ClaimDisputed,
+        // 存证没有争议
+        NotDisputed,
+        // 所有者不能对自己的存证提出争议
+        ChallengerIsOwner,
+        // 存证已被冻结，所有者不能转移或销毁
+        ClaimIsFrozen,
+        // 存证已经被冻结
+        AlreadyFrozen,
+        // 存证没有被冻结
+        NotFrozen,
+        // 批量调用的数量超过 MaxBatchSize
+        BatchTooLarge,
+        // 批量调用中的某一项失败，失败项的序号和错误见 BatchInterrupted 事件
+        BatchItemFailed,
     }
 
     #[pallet::pallet]
@@ -44,63 +417,1497 @@ pub mod pallet {
     pub struct Pallet<T>(_);
 
     #[pallet::storage]
-    pub(super) type Proofs<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, (T::AccountId, T::BlockNumber), ValueQuery>;
+    pub(super) type Proofs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        OptionQuery
+    >;
+
+    // 存证的所有权历史，按时间先后记录 (所有者, 区块高度)
+    #[pallet::storage]
+    #[pallet::getter(fn claim_history)]
+    pub(super) type ClaimHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        Vec<(T::AccountId, T::BlockNumber)>,
+        ValueQuery
+    >;
+
+    // 按所有者索引的存证列表
+    #[pallet::storage]
+    #[pallet::getter(fn claims_by_owner)]
+    pub(super) type ClaimsByOwner<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<Vec<u8>>,
+        ValueQuery
+    >;
+
+    // 哈希存证使用的算法，以带算法标记的存证键为键
+    #[pallet::storage]
+    #[pallet::getter(fn proof_algorithm)]
+    pub(super) type ProofAlgorithms<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        HashAlgorithm,
+        OptionQuery
+    >;
+
+    // 待接受的存证转移：存证 => 接收方
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfer)]
+    pub(super) type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        T::AccountId,
+        OptionQuery
+    >;
+
+    // 账户同意接收其直接转移的发送方. [接收方, 发送方]
+    #[pallet::storage]
+    #[pallet::getter(fn is_sender_approved)]
+    pub(super) type TransferApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery
+    >;
+
+    // 多方共同签署存证的共同所有者及同意情况
+    #[pallet::storage]
+    #[pallet::getter(fn co_signed_claim)]
+    pub(super) type CoSignedClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        CoSignInfo<T::AccountId>,
+        OptionQuery
+    >;
+
+    // 多方共同签署存证上等待同意的操作及已同意的共同所有者
+    #[pallet::storage]
+    #[pallet::getter(fn pending_action)]
+    pub(super) type PendingActions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        (ClaimAction<T::AccountId, T::BlockNumber>, Vec<T::AccountId>),
+        OptionQuery
+    >;
+
+    // 存证附带的描述信息
+    #[pallet::storage]
+    #[pallet::getter(fn claim_metadata)]
+    pub(super) type ClaimMetadataOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        ClaimMetadata,
+        OptionQuery
+    >;
+
+    // 已锚定的批量存证，以默克尔根为键
+    #[pallet::storage]
+    #[pallet::getter(fn batches)]
+    pub(super) type Batches<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BatchInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        OptionQuery
+    >;
+
+    // 尚未揭示的存证承诺，以承诺哈希为键
+    #[pallet::storage]
+    #[pallet::getter(fn commitments)]
+    pub(super) type Commitments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        CommitmentInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        OptionQuery
+    >;
+
+    // 由 root 登记的公证人
+    #[pallet::storage]
+    #[pallet::getter(fn is_notary)]
+    pub(super) type Notaries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery
+    >;
+
+    // 公证人数量
+    #[pallet::storage]
+    #[pallet::getter(fn notary_count)]
+    pub(super) type NotaryCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    // 证明过存证的公证人，按证明顺序排列
+    #[pallet::storage]
+    #[pallet::getter(fn attestations)]
+    pub(super) type Attestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        Vec<T::AccountId>,
+        ValueQuery
+    >;
+
+    // 存在争议的存证
+    #[pallet::storage]
+    #[pallet::getter(fn dispute)]
+    pub(super) type Disputes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        DisputeInfo<T::AccountId, BalanceOf<T>, T::Hash>,
+        OptionQuery
+    >;
+
+    // 被冻结的存证
+    #[pallet::storage]
+    #[pallet::getter(fn is_frozen)]
+    pub(super) type FrozenClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        bool,
+        ValueQuery
+    >;
+
+    // 每个账户通过 create_claim_for 创建存证的次数，用于防止签名重放
+    #[pallet::storage]
+    #[pallet::getter(fn nonce_of)]
+    pub(super) type Nonces<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery
+    >;
+
+    // 通过 create_claim_for 创建的存证，质押押金的交易发送方，存证移除时退还
+    #[pallet::storage]
+    #[pallet::getter(fn claim_depositor)]
+    pub(super) type ClaimDepositors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        T::AccountId,
+        OptionQuery
+    >;
+
+    // 按过期区块索引的存证，每个区块最多 MaxExpiriesPerBlock 个
+    #[pallet::storage]
+    #[pallet::getter(fn claim_expiries)]
+    pub(super) type ClaimExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        Vec<Vec<u8>>,
+        ValueQuery
+    >;
+
+    // 当前使用的存储结构版本
+    #[pallet::storage]
+    pub(super) type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        // 创世时登记的存证及其所有者，所有者照常质押押金
+        pub claims: Vec<(Vec<u8>, T::AccountId)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { claims: Default::default() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            // 新链直接使用最新的存储结构
+            StorageVersion::<T>::put(Releases::V2_0_0);
+            // 登记创世存证，存证长度不能超过 StringLimit，且不能重复
+            for (proof, owner) in &self.claims {
+                assert!(
+                    proof.len() <= T::StringLimit::get() as usize,
+                    "genesis claim is longer than StringLimit"
+                );
+                assert!(!Proofs::<T>::contains_key(proof), "duplicate claim in genesis");
+                Pallet::<T>::insert_claim(owner, proof.clone(), Zero::zero(), 0, None)
+                    .expect("genesis claim owners can afford their claims");
+            }
+        }
+    }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        // 清理在区块n过期的存证，安排过期时已保证每个区块最多 MaxExpiriesPerBlock 个
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            if !ClaimExpiries::<T>::contains_key(n) {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let due = ClaimExpiries::<T>::take(n);
+
+            let mut pruned: Weight = 0;
+            for proof in due.iter() {
+                let claim = match Proofs::<T>::get(proof) {
+                    Some(claim) => claim,
+                    None => continue,
+                };
+                // 存证可能已经续期
+                if !matches!(claim.expiry, Some(expiry) if expiry <= n) {
+                    continue;
+                }
+                // 存在争议或被冻结的存证在裁定或解冻后重新安排过期
+                if Disputes::<T>::contains_key(proof) || FrozenClaims::<T>::get(proof) {
+                    continue;
+                }
+                Self::remove_claim(proof, &claim);
+                Self::deposit_event(Event::ClaimExpired(claim.owner, proof.clone()));
+                pruned += 1;
+            }
+
+            T::DbWeight::get().reads_writes(
+                2 + due.len() as Weight * 3 + pruned * 3,
+                2 + pruned * 4,
+            )
+        }
+
+        // 哈希存证的键也受 StringLimit 限制，所有权历史至少保留创建记录和当前所有者，
+        // 每个区块至少能有一个存证过期
+        fn integrity_test() {
+            assert!(
+                T::StringLimit::get() >= HASHED_PROOF_LENGTH,
+                "StringLimit is shorter than the key of a hashed claim"
+            );
+            assert!(
+                T::MaxHistoryLength::get() >= 2,
+                "MaxHistoryLength cannot hold both the creator and the owner of a claim"
+            );
+            assert!(T::MaxExpiriesPerBlock::get() > 0, "MaxExpiriesPerBlock leaves no room for expiring claims");
+        }
+
+        // 升级时将旧的存证转换为 ClaimInfo
+        fn on_runtime_upgrade() -> Weight {
+            crate::migrations::migrate_to_v2::<T>()
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(1_000)]
-        pub(super) fn create_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
-            // 存证限制长度
-            ensure!(proof.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+        #[pallet::weight(T::WeightInfo::create_claim(
+            proof.len() as u32,
+            metadata.as_ref().map_or(0, |metadata| metadata.encoded_size() as u32),
+        ))]
+        pub(super) fn create_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
+        ) -> DispatchResultWithPostInfo {
             // 验证签名
             let sender = ensure_signed(origin)?;
-            // 验证存证是否已经存在，存在则返回Proof已存在错误
-            ensure!(!Proofs::<T>::contains_key(&proof), Error::<T>::ProofAlreadyClaimed);
+            // 存证限制有效期
+            Self::ensure_valid_expiry(expiry)?;
+            // 描述信息限制长度
+            if let Some(metadata) = &metadata {
+                Self::ensure_valid_metadata(metadata)?;
+            }
+            // 余额需要足够同时质押存证和描述信息的押金
+            let metadata_deposit = metadata.as_ref().map_or_else(Zero::zero, Self::metadata_deposit);
+            ensure!(
+                T::Currency::can_reserve(&sender, Self::claim_deposit(&proof).saturating_add(metadata_deposit)),
+                Error::<T>::InsufficientBalance
+            );
             // 获取当前block值
             let current_block = <frame_system::Module<T>>::block_number();
             // 插入存证
-            Proofs::<T>::insert(&proof, (&sender, current_block));
-            // 触发相应事件
-            Self::deposit_event(Event::ClaimCreated(sender, proof));
+            Self::insert_claim(&sender, proof.clone(), current_block, Self::now(), expiry)?;
+            // 保存描述信息并质押其押金
+            if metadata.is_some() {
+                Self::update_metadata(&proof, metadata)?;
+            }
             // 返回OK状态
             Ok(().into())
         }
 
-        #[pallet::weight(10_000)]
-        pub fn revoke_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+        // 批量创建存证，全部成功或全部回滚，失败项的序号和错误见 BatchInterrupted 事件
+        #[pallet::weight(T::WeightInfo::create_claims(proofs.len() as u32))]
+        pub fn create_claims(origin: OriginFor<T>, proofs: Vec<Vec<u8>>) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
-            // 验证签名是否存在，不存在则返回无该存证错误
+            // 批量数量限制
+            ensure!(proofs.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+            let current_block = <frame_system::Module<T>>::block_number();
+            let now = Self::now();
+            Self::do_batch(proofs, |proof| Self::insert_claim(&sender, proof, current_block, now, None))
+        }
+
+        // 批量销毁存证，全部成功或全部回滚
+        #[pallet::weight(T::WeightInfo::revoke_claims(proofs.len() as u32))]
+        pub fn revoke_claims(origin: OriginFor<T>, proofs: Vec<Vec<u8>>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 批量数量限制
+            ensure!(proofs.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+            Self::do_batch(proofs, |proof| Self::do_revoke(sender.clone(), proof))
+        }
+
+        // 批量将存证转移给对应的账户，全部成功或全部回滚
+        #[pallet::weight(T::WeightInfo::transfer_claims(transfers.len() as u32))]
+        pub fn transfer_claims(origin: OriginFor<T>, transfers: Vec<(Vec<u8>, T::AccountId)>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 批量数量限制
+            ensure!(transfers.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+            Self::do_batch(transfers, |(proof, dist)| Self::do_transfer_claim(sender.clone(), proof, dist))
+        }
+
+        // 代替 owner 创建存证，owner 在链下对存证签名
+        // signature 是 owner 对 delegated_claim_payload（存证、过期区块、owner 当前的 nonce 和截止区块）的签名
+        // 交易发送方支付手续费和押金，押金质押在交易发送方账户，存证移除时退还给交易发送方
+        #[pallet::weight(T::WeightInfo::create_claim_for(proof.len() as u32))]
+        pub fn create_claim_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            proof: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+            deadline: T::BlockNumber,
+            signature: T::Signature,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+            // 验证签名是否过期
+            let current_block = <frame_system::Module<T>>::block_number();
+            ensure!(current_block <= deadline, Error::<T>::SignatureExpired);
+            // 验证 owner 的签名
+            let nonce = Nonces::<T>::get(&owner);
+            let payload = Self::delegated_claim_payload(&proof, expiry, nonce, deadline);
+            ensure!(signature.verify(&payload[..], &owner), Error::<T>::InvalidSignature);
+            // 存证限制有效期
+            Self::ensure_valid_expiry(expiry)?;
+            // 验证存证能否创建
+            Self::ensure_claimable(&owner, &proof)?;
+            ensure!(
+                T::Currency::can_reserve(&relayer, Self::claim_deposit(&proof)),
+                Error::<T>::InsufficientBalance
+            );
+            // 增加 nonce，签名不能重放
+            Nonces::<T>::insert(&owner, nonce + 1);
+            // 插入存证，由交易发送方质押押金
+            Self::insert_claim_paid_by(&owner, &relayer, proof, current_block, Self::now(), expiry)?;
+
+            Ok(().into())
+        }
+
+        // 创建多方共同签署的存证，创建人自动成为共同所有者并计为第一个同意
+        // 达到门限数量的共同所有者同意后存证确认，销毁和转移同样需要门限数量的共同所有者同意
+        #[pallet::weight(T::WeightInfo::create_cosigned_claim(
+            proof.len() as u32,
+            co_owners.len() as u32 + 1,
+        ))]
+        pub fn create_cosigned_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            co_owners: Vec<T::AccountId>,
+            threshold: u32,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 创建人加入共同所有者
+            let mut co_owners = co_owners;
+            if !co_owners.contains(&sender) {
+                co_owners.insert(0, sender.clone());
+            }
+            // 共同所有者限制数量
+            ensure!(co_owners.len() <= T::MaxCoOwners::get() as usize, Error::<T>::TooManyCoOwners);
+            // 共同所有者不能重复
+            let mut distinct = co_owners.clone();
+            distinct.sort();
+            distinct.dedup();
+            ensure!(distinct.len() == co_owners.len(), Error::<T>::DuplicateCoOwner);
+            // 门限必须在1和共同所有者数量之间
+            ensure!(
+                threshold > 0 && threshold as usize <= co_owners.len(),
+                Error::<T>::InvalidThreshold
+            );
+            // 存证限制有效期
+            Self::ensure_valid_expiry(expiry)?;
+            // 插入存证，押金由创建人质押
+            let current_block = <frame_system::Module<T>>::block_number();
+            Self::insert_claim(&sender, proof.clone(), current_block, Self::now(), expiry)?;
+            // 记录共同所有者，门限为1时直接确认
+            let status = if threshold == 1 { ClaimStatus::Finalized } else { ClaimStatus::Pending };
+            CoSignedClaims::<T>::insert(&proof, CoSignInfo {
+                co_owners,
+                threshold,
+                approvals: vec![sender],
+                status,
+            });
+            if status == ClaimStatus::Finalized {
+                Self::deposit_event(Event::ClaimFinalized(proof));
+            }
+
+            Ok(().into())
+        }
+
+        // 共同所有者同意多方共同签署的存证
+        #[pallet::weight(T::WeightInfo::approve_claim(T::MaxCoOwners::get()))]
+        pub fn approve_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证存证是否存在
             ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
-            // 获取存证信息
-            let (owner, _) = Proofs::<T>::get(&proof);
-            // 验证请求发送人是否存证所有者，不是则报错
-            ensure!(owner == sender, Error::<T>::NotProofOwner);
-            // 移除存证
-            Proofs::<T>::remove(&proof);
-            // 触发事件
-            Self::deposit_event(Event::ClaimRevoked(sender, proof));
+            // 单一所有者的存证创建后即确认
+            let mut info = CoSignedClaims::<T>::get(&proof).ok_or(Error::<T>::AlreadyFinalized)?;
+            ensure!(info.status == ClaimStatus::Pending, Error::<T>::AlreadyFinalized);
+            // 只有共同所有者可以同意，且每人只能同意一次
+            ensure!(info.co_owners.contains(&sender), Error::<T>::NotCoOwner);
+            ensure!(!info.approvals.contains(&sender), Error::<T>::AlreadyApproved);
+            // 记录同意，达到门限时确认存证
+            info.approvals.push(sender.clone());
+            let finalized = info.approvals.len() as u32 >= info.threshold;
+            if finalized {
+                info.status = ClaimStatus::Finalized;
+            }
+            CoSignedClaims::<T>::insert(&proof, info);
+            // 触发同意和确认事件
+            Self::deposit_event(Event::ClaimApproved(sender, proof.clone()));
+            if finalized {
+                Self::deposit_event(Event::ClaimFinalized(proof));
+            }
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(T::WeightInfo::revoke_claim(proof.len() as u32))]
+        pub fn revoke_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 销毁存证
+            Self::do_revoke(sender, proof)?;
             // 返回OK
             Ok(().into())
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::transfer_claim(proof.len() as u32))]
         pub fn transfer_claim(origin: OriginFor<T>, proof: Vec<u8>, dist: T::AccountId) -> DispatchResultWithPostInfo {
             // 交易发送方、存证、转移地址
             // 验证交易时被签名的
             let sender = ensure_signed(origin)?;
-            // 验证签名是否存在，不存在则返回无该存证错误
+            // 转移存证
+            Self::do_transfer_claim(sender, proof, dist)?;
+
+            Ok(().into())
+        }
+
+        // 向 to 发起存证转移，需要对方调用 accept_claim 接受
+        #[pallet::weight(T::WeightInfo::offer_claim(proof.len() as u32))]
+        pub fn offer_claim(origin: OriginFor<T>, proof: Vec<u8>, to: T::AccountId) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证存证是否存在
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            // 只有所有者可以发起转移，新的转移会覆盖之前未接受的转移
+            ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+            // 多方共同签署的存证只能通过 transfer_claim 转移
+            ensure!(!CoSignedClaims::<T>::contains_key(&proof), Error::<T>::CoSignedClaim);
+            // 存在争议或被冻结的存证不能变更
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            ensure!(to != sender, Error::<T>::TransferToSelf);
+            PendingTransfers::<T>::insert(&proof, &to);
+            // 触发发起转移事件
+            Self::deposit_event(Event::TransferOffered(sender, to, proof));
+
+            Ok(().into())
+        }
+
+        // 接受发给自己的存证转移
+        #[pallet::weight(T::WeightInfo::accept_claim(proof.len() as u32))]
+        pub fn accept_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证是否有待接受的转移
+            let to = PendingTransfers::<T>::get(&proof).ok_or(Error::<T>::NoPendingTransfer)?;
+            // 只有接收方可以接受
+            ensure!(to == sender, Error::<T>::NotTransferRecipient);
+            // 存在争议或被冻结的存证不能变更
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            // 存证被移除时会同时移除待接受的转移，所以存证一定存在
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            // 转移存证
+            Self::do_transfer(claim, sender, proof)?;
+
+            Ok(().into())
+        }
+
+        // 取消尚未被接受的存证转移
+        #[pallet::weight(T::WeightInfo::cancel_offer(proof.len() as u32))]
+        pub fn cancel_offer(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证是否有待接受的转移
+            let to = PendingTransfers::<T>::get(&proof).ok_or(Error::<T>::NoPendingTransfer)?;
+            // 只有所有者可以取消
+            ensure!(
+                Proofs::<T>::get(&proof).map_or(false, |claim| claim.owner == sender),
+                Error::<T>::NotProofOwner
+            );
+            PendingTransfers::<T>::remove(&proof);
+            // 触发取消转移事件
+            Self::deposit_event(Event::TransferCancelled(sender, to, proof));
+
+            Ok(().into())
+        }
+
+        // 同意 sender 直接向自己转移存证
+        #[pallet::weight(T::WeightInfo::approve_sender())]
+        pub fn approve_sender(origin: OriginFor<T>, sender: T::AccountId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            TransferApprovals::<T>::insert(&who, &sender, true);
+            // 触发同意事件
+            Self::deposit_event(Event::SenderApproved(who, sender));
+
+            Ok(().into())
+        }
+
+        // 撤销对 sender 的同意
+        #[pallet::weight(T::WeightInfo::unapprove_sender())]
+        pub fn unapprove_sender(origin: OriginFor<T>, sender: T::AccountId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            TransferApprovals::<T>::remove(&who, &sender);
+            // 触发撤销同意事件
+            Self::deposit_event(Event::SenderUnapproved(who, sender));
+
+            Ok(().into())
+        }
+
+        // 修改存证的过期区块，None 表示永不过期
+        // 多方共同签署的存证在门限数量的共同所有者同意后才修改
+        #[pallet::weight(T::WeightInfo::renew_claim(proof.len() as u32))]
+        pub fn renew_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证新的过期区块
+            Self::ensure_valid_expiry(expiry)?;
+            // 验证存证是否存在
+            let mut claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            // 存在争议或被冻结的存证不能修改过期区块，否则所有者可以让存证过期并取回全部押金
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            // 续期到原过期区块时保留原来的位置
+            if let Some(new_expiry) = expiry.filter(|_| expiry != claim.expiry) {
+                Self::ensure_expiry_slot(new_expiry)?;
+            }
+            if let Some(info) = CoSignedClaims::<T>::get(&proof) {
+                // 多方共同签署的存证在门限数量的共同所有者同意后才续期
+                if !Self::approve_action(&sender, &proof, &info, ClaimAction::Renew(expiry))? {
+                    return Ok(().into());
+                }
+                PendingActions::<T>::remove(&proof);
+            } else {
+                // 只有所有者可以续期
+                ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+            }
+            // 从原过期区块移到新的过期区块
+            if let Some(old_expiry) = claim.expiry {
+                Self::remove_from_expiries(old_expiry, &proof);
+            }
+            if let Some(new_expiry) = expiry {
+                ClaimExpiries::<T>::append(new_expiry, &proof);
+            }
+            claim.expiry = expiry;
+            let owner = claim.owner.clone();
+            Proofs::<T>::insert(&proof, claim);
+            // 触发续期事件
+            Self::deposit_event(Event::ClaimRenewed(owner, proof, expiry));
+
+            Ok(().into())
+        }
+
+        // 所有者设置存证的描述信息，None 表示清除
+        #[pallet::weight(T::WeightInfo::set_claim_metadata(
+            proof.len() as u32,
+            metadata.as_ref().map_or(0, |metadata| metadata.encoded_size() as u32),
+        ))]
+        pub fn set_claim_metadata(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            metadata: Option<ClaimMetadata>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证存证是否存在
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            // 只有所有者可以设置
+            ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+            // 存在争议或被冻结的存证不能变更
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            // 描述信息限制长度
+            if let Some(metadata) = &metadata {
+                Self::ensure_valid_metadata(metadata)?;
+            }
+            // 按新旧描述信息的押金差额补充质押或退还
+            Self::update_metadata(&proof, metadata)?;
+            // 触发描述信息更新事件
+            Self::deposit_event(Event::ClaimMetadataUpdated(sender, proof));
+
+            Ok(().into())
+        }
+
+        // 锚定一批（leaf_count 个）文件哈希的默克尔根
+        #[pallet::weight(T::WeightInfo::anchor_batch())]
+        pub fn anchor_batch(origin: OriginFor<T>, root: T::Hash, leaf_count: u32) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 批量不能为空
+            ensure!(leaf_count > 0, Error::<T>::EmptyBatch);
+            // 验证默克尔根是否已被锚定
+            ensure!(!Batches::<T>::contains_key(&root), Error::<T>::BatchAlreadyAnchored);
+            // 默克尔根按同样长度的存证质押押金
+            let deposit = Self::claim_deposit(root.as_ref());
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+            Batches::<T>::insert(&root, BatchInfo {
+                owner: sender.clone(),
+                block_number: <frame_system::Module<T>>::block_number(),
+                moment: Self::now(),
+                leaf_count,
+                deposit,
+            });
+            // 触发锚定事件
+            Self::deposit_event(Event::BatchAnchored(sender, root, leaf_count));
+
+            Ok(().into())
+        }
+
+        // 销毁批量存证并退还押金，已单独登记的存证不受影响
+        #[pallet::weight(T::WeightInfo::revoke_batch())]
+        pub fn revoke_batch(origin: OriginFor<T>, root: T::Hash) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证默克尔根是否存在
+            let batch = Batches::<T>::get(&root).ok_or(Error::<T>::NoSuchBatch)?;
+            // 只有所有者可以销毁
+            ensure!(batch.owner == sender, Error::<T>::NotProofOwner);
+            Batches::<T>::remove(&root);
+            T::Currency::unreserve(&sender, batch.deposit);
+            // 触发销毁事件
+            Self::deposit_event(Event::BatchRevoked(sender, root));
+
+            Ok(().into())
+        }
+
+        // 将批量中的某个文件登记为单独的存证，区块高度沿用锚定时的高度
+        #[pallet::weight(T::WeightInfo::claim_from_batch(proof.len() as u32))]
+        pub fn claim_from_batch(
+            origin: OriginFor<T>,
+            root: T::Hash,
+            leaf: T::Hash,
+            proof: Vec<T::Hash>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证默克尔根是否存在
+            let batch = Batches::<T>::get(&root).ok_or(Error::<T>::NoSuchBatch)?;
+            // 只有所有者可以登记
+            ensure!(batch.owner == sender, Error::<T>::NotProofOwner);
+            // 验证包含证明
+            ensure!(Self::verify_inclusion(root, leaf, &proof), Error::<T>::InvalidMerkleProof);
+            // 插入存证
+            Self::insert_claim(&sender, leaf.as_ref().to_vec(), batch.block_number, batch.moment, None)?;
+
+            Ok(().into())
+        }
+
+        // 提交存证承诺而不公开存证，commitment 为 commitment_of(存证, 盐值, 发送方)
+        // 在 RevealWindow 个区块内可以通过 reveal_claim 揭示，存证的区块高度沿用提交承诺时的高度
+        #[pallet::weight(T::WeightInfo::commit_claim())]
+        pub fn commit_claim(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证承诺是否已经提交
+            ensure!(!Commitments::<T>::contains_key(&commitment), Error::<T>::CommitmentAlreadyExists);
+            // 承诺按同样长度的存证质押押金
+            let deposit = Self::claim_deposit(commitment.as_ref());
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+            Commitments::<T>::insert(&commitment, CommitmentInfo {
+                owner: sender.clone(),
+                block_number: <frame_system::Module<T>>::block_number(),
+                moment: Self::now(),
+                deposit,
+            });
+            // 触发提交承诺事件
+            Self::deposit_event(Event::ClaimCommitted(sender, commitment));
+
+            Ok(().into())
+        }
+
+        // 揭示发送方提交的承诺对应的存证和盐值，并登记存证
+        #[pallet::weight(T::WeightInfo::reveal_claim(proof.len() as u32, salt.len() as u32))]
+        pub fn reveal_claim(origin: OriginFor<T>, proof: Vec<u8>, salt: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证承诺是否存在
+            let commitment = Self::commitment_of(&proof, &salt, &sender);
+            let commit = Commitments::<T>::get(&commitment).ok_or(Error::<T>::NoSuchCommitment)?;
+            // 承诺必须由发送方提交，而不是被其他账户复制后抢先提交
+            ensure!(commit.owner == sender, Error::<T>::NoSuchCommitment);
+            // 验证是否仍在揭示期限内
+            let current_block = <frame_system::Module<T>>::block_number();
+            ensure!(
+                current_block <= commit.block_number.saturating_add(T::RevealWindow::get()),
+                Error::<T>::RevealWindowClosed
+            );
+            // 验证存证能否创建
+            Self::ensure_claimable(&sender, &proof)?;
+            // 先退还承诺的押金，再质押存证的押金
+            ensure!(
+                T::Currency::can_reserve(&sender, Self::claim_deposit(&proof).saturating_sub(commit.deposit)),
+                Error::<T>::InsufficientBalance
+            );
+            T::Currency::unreserve(&commit.owner, commit.deposit);
+            Commitments::<T>::remove(&commitment);
+            // 插入存证，区块高度和时间沿用提交承诺时的值
+            Self::insert_claim(&sender, proof.clone(), commit.block_number, commit.moment, None)?;
+            // 触发揭示事件
+            Self::deposit_event(Event::ClaimRevealed(sender, commitment, proof));
+
+            Ok(().into())
+        }
+
+        // 清理超过揭示期限仍未揭示的承诺，押金退还给所有者，任何账户都可以调用
+        #[pallet::weight(T::WeightInfo::reap_commitment())]
+        pub fn reap_commitment(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            // 验证承诺是否存在
+            let commit = Commitments::<T>::get(&commitment).ok_or(Error::<T>::NoSuchCommitment)?;
+            // 验证是否已超过揭示期限
+            let current_block = <frame_system::Module<T>>::block_number();
+            ensure!(
+                current_block > commit.block_number.saturating_add(T::RevealWindow::get()),
+                Error::<T>::RevealWindowOpen
+            );
+            Commitments::<T>::remove(&commitment);
+            T::Currency::unreserve(&commit.owner, commit.deposit);
+            // 触发清理事件
+            Self::deposit_event(Event::CommitmentReaped(commit.owner, commitment));
+
+            Ok(().into())
+        }
+
+        // 登记公证人，只能由 root 调用
+        #[pallet::weight(T::WeightInfo::add_notary())]
+        pub fn add_notary(origin: OriginFor<T>, notary: T::AccountId) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            // 验证是否已经是公证人
+            ensure!(!Notaries::<T>::get(&notary), Error::<T>::AlreadyNotary);
+            // 验证公证人数量是否已达上限
+            let count = NotaryCount::<T>::get();
+            ensure!(count < T::MaxNotaries::get(), Error::<T>::TooManyNotaries);
+            Notaries::<T>::insert(&notary, true);
+            NotaryCount::<T>::put(count + 1);
+            // 触发添加公证人事件
+            Self::deposit_event(Event::NotaryAdded(notary));
+
+            Ok(().into())
+        }
+
+        // 移除公证人，只能由 root 调用，已有的证明保留但不再计入验证
+        #[pallet::weight(T::WeightInfo::remove_notary())]
+        pub fn remove_notary(origin: OriginFor<T>, notary: T::AccountId) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            // 验证是否是公证人
+            ensure!(Notaries::<T>::get(&notary), Error::<T>::NotNotary);
+            Notaries::<T>::remove(&notary);
+            NotaryCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            // 触发移除公证人事件
+            Self::deposit_event(Event::NotaryRemoved(notary));
+
+            Ok(().into())
+        }
+
+        // 公证人证明存证，达到 AttestationThreshold 个公证人证明后存证被视为已验证
+        #[pallet::weight(T::WeightInfo::attest_claim(proof.len() as u32))]
+        pub fn attest_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 只有公证人可以证明
+            ensure!(Notaries::<T>::get(&sender), Error::<T>::NotNotary);
+            // 验证存证是否存在
             ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
-            // 获取存证信息
-            let (owner, _block_number) = Proofs::<T>::get(&proof);
-            // 验证请求发送人是否存证所有者，不是则报错
-            ensure!(owner == sender, Error::<T>::NotProofOwner);
-            // 将存证插入目的地址，使用方法获取当前块
-            Proofs::<T>::insert(&proof, (dist, <frame_system::Module<T>>::block_number()));
+            // 同一个公证人只能证明一次
+            let mut attestations = Attestations::<T>::get(&proof);
+            ensure!(!attestations.contains(&sender), Error::<T>::AlreadyAttested);
+            attestations.push(sender.clone());
+            Attestations::<T>::insert(&proof, attestations);
+            // 触发证明事件
+            Self::deposit_event(Event::ClaimAttested(sender, proof));
+
+            Ok(().into())
+        }
+
+        // 对存证所有权提出争议，质押 ChallengeBond 保证金
+        // 争议由 root 通过 resolve_dispute 解决之前，存证不能转移或销毁
+        #[pallet::weight(T::WeightInfo::challenge_claim(proof.len() as u32))]
+        pub fn challenge_claim(origin: OriginFor<T>, proof: Vec<u8>, evidence: T::Hash) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 验证存证是否存在
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            // 所有者不能对自己的存证提出争议
+            ensure!(claim.owner != sender, Error::<T>::ChallengerIsOwner);
+            // 同一时间只能有一个争议
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            // 质押保证金
+            let bond = T::ChallengeBond::get();
+            T::Currency::reserve(&sender, bond).map_err(|_| Error::<T>::InsufficientBalance)?;
+            Disputes::<T>::insert(&proof, DisputeInfo {
+                challenger: sender.clone(),
+                evidence,
+                bond,
+            });
+            // 触发争议事件
+            Self::deposit_event(Event::ClaimChallenged(sender, proof, evidence));
+
+            Ok(().into())
+        }
+
+        // 裁定存证争议，只能由 root 调用
+        // uphold 为 true 时维持原所有者，罚没争议人的保证金
+        // 否则将存证改判给争议人并保留原来的区块高度，罚没原所有者的押金，争议人的保证金作为存证的押金
+        // 争议人拥有的存证数量已达上限时移除存证，罚没原所有者的押金并退还争议人的保证金
+        #[pallet::weight(T::WeightInfo::resolve_dispute(proof.len() as u32))]
+        pub fn resolve_dispute(origin: OriginFor<T>, proof: Vec<u8>, uphold: bool) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            // 验证存证是否存在争议
+            let dispute = Disputes::<T>::get(&proof).ok_or(Error::<T>::NotDisputed)?;
+            // 存证被移除时会同时移除争议，所以存证一定存在
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            if uphold {
+                // 维持原所有者，罚没争议人的保证金
+                Disputes::<T>::remove(&proof);
+                T::Currency::slash_reserved(&dispute.challenger, dispute.bond);
+                // 争议期间到期的存证在下一个区块移除
+                Self::reschedule_if_expired(&proof, claim.expiry);
+                Self::deposit_event(Event::DisputeUpheld(claim.owner, dispute.challenger, proof));
+                return Ok(().into());
+            }
+            // 罚没原所有者的押金
+            T::Currency::slash_reserved(&Self::deposit_holder(&proof, &claim.owner), claim.deposit);
+            let mut challenger_owned = ClaimsByOwner::<T>::get(&dispute.challenger);
+            // 争议人拥有的存证数量已达上限时，移除存证并退还争议人的保证金
+            if challenger_owned.len() >= T::MaxClaimsPerAccount::get() as usize {
+                Self::remove_claim(&proof, &ClaimInfo { deposit: Zero::zero(), ..claim.clone() });
+                Self::deposit_event(Event::ClaimVoided(claim.owner, dispute.challenger, proof));
+                return Ok(().into());
+            }
+            // 争议人的保证金作为存证的押金
+            Disputes::<T>::remove(&proof);
+            ClaimDepositors::<T>::remove(&proof);
+            Proofs::<T>::insert(&proof, ClaimInfo {
+                owner: dispute.challenger.clone(),
+                deposit: dispute.bond,
+                ..claim.clone()
+            });
+            // 记录所有权历史并更新所有者索引
+            Self::record_owner(&proof, &dispute.challenger, <frame_system::Module<T>>::block_number());
+            Self::remove_from_owner(&claim.owner, &proof);
+            challenger_owned.push(proof.clone());
+            ClaimsByOwner::<T>::insert(&dispute.challenger, challenger_owned);
+            // 原所有者发起的转移和共同签署信息一并移除
+            PendingTransfers::<T>::remove(&proof);
+            CoSignedClaims::<T>::remove(&proof);
+            PendingActions::<T>::remove(&proof);
+            Self::reschedule_if_expired(&proof, claim.expiry);
+            // 触发改判事件
+            Self::deposit_event(Event::ClaimReassigned(claim.owner, dispute.challenger, proof));
+
+            Ok(().into())
+        }
+
+        // 强制销毁存证并退还押金，只能由 ForceOrigin 调用
+        #[pallet::weight(T::WeightInfo::force_revoke(proof.len() as u32))]
+        pub fn force_revoke(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            // 移除存证并退还押金
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            Self::remove_claim(&proof, &claim);
+            // 触发强制销毁事件
+            Self::deposit_event(Event::ClaimForceRevoked(claim.owner, proof));
+
+            Ok(().into())
+        }
+
+        // 强制转移存证，不需要接收方事先同意，只能由 ForceOrigin 调用
+        // 存证的争议一并撤销，并退还争议人的保证金
+        // 在 ClaimTransferred 事件之后触发 ClaimForceTransferred 事件
+        #[pallet::weight(T::WeightInfo::force_transfer(proof.len() as u32))]
+        pub fn force_transfer(origin: OriginFor<T>, proof: Vec<u8>, dist: T::AccountId) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            // 转移存证
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            let from = claim.owner.clone();
+            let expiry = claim.expiry;
+            Self::do_transfer(claim, dist.clone(), proof.clone())?;
+            // 争议随强制转移一并撤销，退还争议人的保证金
+            if let Some(dispute) = Disputes::<T>::take(&proof) {
+                T::Currency::unreserve(&dispute.challenger, dispute.bond);
+                Self::reschedule_if_expired(&proof, expiry);
+            }
+            // 触发强制转移事件
+            Self::deposit_event(Event::ClaimForceTransferred(from, dist, proof));
+
+            Ok(().into())
+        }
+
+        // 冻结存证，冻结后所有者不能转移、销毁、续期或修改描述信息，存证也不会过期，只能由 ForceOrigin 调用
+        #[pallet::weight(T::WeightInfo::freeze_claim(proof.len() as u32))]
+        pub fn freeze_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            // 验证存证是否存在
+            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+            // 验证存证是否已被冻结
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::AlreadyFrozen);
+            FrozenClaims::<T>::insert(&proof, true);
+            // 触发冻结事件
+            Self::deposit_event(Event::ClaimFrozen(proof));
+
+            Ok(().into())
+        }
+
+        // 解冻存证，冻结期间已经过期的存证在下一个区块移除，只能由 ForceOrigin 调用
+        #[pallet::weight(T::WeightInfo::thaw_claim(proof.len() as u32))]
+        pub fn thaw_claim(origin: OriginFor<T>, proof: Vec<u8>) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            // 验证存证是否已被冻结
+            ensure!(FrozenClaims::<T>::get(&proof), Error::<T>::NotFrozen);
+            FrozenClaims::<T>::remove(&proof);
+            // 冻结期间到期的存证在下一个区块移除
+            if let Some(claim) = Proofs::<T>::get(&proof) {
+                Self::reschedule_if_expired(&proof, claim.expiry);
+            }
+            // 触发解冻事件
+            Self::deposit_event(Event::ClaimThawed(proof));
+
+            Ok(().into())
+        }
+
+        // 以32字节摘要登记存证，并记录所用的哈希算法
+        // 存证键为带算法标记的摘要 HashAlgorithm::tagged
+        #[pallet::weight(T::WeightInfo::create_hashed_claim())]
+        pub fn create_hashed_claim(
+            origin: OriginFor<T>,
+            algorithm: HashAlgorithm,
+            digest: [u8; 32],
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 插入哈希存证
+            Self::insert_hashed_claim(&sender, algorithm, digest, expiry)?;
+
+            Ok(().into())
+        }
+
+        // 在链上计算内容的摘要，并以摘要登记存证
+        #[pallet::weight(T::WeightInfo::hash_and_claim(content.len() as u32))]
+        pub fn hash_and_claim(
+            origin: OriginFor<T>,
+            algorithm: HashAlgorithm,
+            content: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            // 内容限制长度
+            ensure!(content.len() <= T::MaxContentLength::get() as usize, Error::<T>::ContentTooLong);
+            // 计算摘要并插入哈希存证
+            Self::insert_hashed_claim(&sender, algorithm, algorithm.hash(&content), expiry)?;
 
             Ok(().into())
         }
     }
-}
\ No newline at end of file
+
+    impl<T: Config> Pallet<T> {
+        // 当前的 Unix 时间（毫秒）
+        pub fn now() -> u64 {
+            T::UnixTime::now().as_millis() as u64
+        }
+
+        // 计算存证所需押金：基础押金 + 每字节押金 * 长度
+        pub fn claim_deposit(proof: &[u8]) -> BalanceOf<T> {
+            let bytes: BalanceOf<T> = (proof.len() as u32).into();
+            T::ClaimDepositBase::get()
+                .saturating_add(T::ClaimDepositPerByte::get().saturating_mul(bytes))
+        }
+
+        // 分页查询账户拥有的存证，page 从0开始
+        pub fn claims_of(owner: &T::AccountId, page: u32) -> Vec<Vec<u8>> {
+            let start = page.saturating_mul(CLAIMS_PAGE_SIZE) as usize;
+            ClaimsByOwner::<T>::get(owner)
+                .into_iter()
+                .skip(start)
+                .take(CLAIMS_PAGE_SIZE as usize)
+                .collect()
+        }
+
+        // 验证 leaf 是否属于以 root 为根的已锚定批量
+        // proof 为从叶子到根的兄弟节点哈希
+        // 叶子按 MERKLE_LEAF_PREFIX || leaf 哈希，每对节点按从小到大的顺序以 MERKLE_NODE_PREFIX || left || right 哈希
+        // 因此中间节点不能冒充文件
+        pub fn verify_inclusion(root: T::Hash, leaf: T::Hash, proof: &[T::Hash]) -> bool {
+            let batch = match Batches::<T>::get(&root) {
+                Some(batch) => batch,
+                None => return false,
+            };
+            // 证明长度不能超过树的深度
+            if proof.len() as u32 > Self::merkle_depth(batch.leaf_count) {
+                return false;
+            }
+            let leaf = T::Hashing::hash_of(&(MERKLE_LEAF_PREFIX, leaf));
+            let computed = proof.iter().fold(leaf, |node, sibling| {
+                if node <= *sibling {
+                    T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, node, *sibling))
+                } else {
+                    T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, *sibling, node))
+                }
+            });
+            computed == root
+        }
+
+        // leaf_count 个叶子的默克尔树深度
+        fn merkle_depth(leaf_count: u32) -> u32 {
+            match leaf_count {
+                0 | 1 => 0,
+                n => MAX_MERKLE_DEPTH - (n - 1).leading_zeros(),
+            }
+        }
+
+        // 在存储事务中对每一项执行 f，任一项失败则整体回滚
+        fn do_batch<I>(items: Vec<I>, mut f: impl FnMut(I) -> DispatchResult) -> DispatchResultWithPostInfo {
+            let result = with_transaction(|| {
+                for (index, item) in items.into_iter().enumerate() {
+                    if let Err(error) = f(item) {
+                        return TransactionOutcome::Rollback(Err((index as u32, error)));
+                    }
+                }
+                TransactionOutcome::Commit(Ok(()))
+            });
+            if let Err((index, error)) = result {
+                // 在回滚之后触发事件，报告失败项的序号和错误
+                Self::deposit_event(Event::BatchInterrupted(index, error));
+                return Err(Error::<T>::BatchItemFailed.into());
+            }
+
+            Ok(().into())
+        }
+
+        // 以 sender 的身份销毁存证
+        fn do_revoke(sender: T::AccountId, proof: Vec<u8>) -> DispatchResult {
+            // 验证签名是否存在，不存在则返回无该存证错误
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            // 存在争议或被冻结的存证不能变更
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            if let Some(info) = CoSignedClaims::<T>::get(&proof) {
+                // 多方共同签署的存证在门限数量的共同所有者同意后才销毁
+                if !Self::approve_action(&sender, &proof, &info, ClaimAction::Revoke)? {
+                    return Ok(());
+                }
+            } else {
+                // 验证请求发送人是否存证所有者，不是则报错
+                ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+            }
+            // 移除存证并退还押金
+            Self::remove_claim(&proof, &claim);
+            // 触发事件
+            Self::deposit_event(Event::ClaimRevoked(sender, proof));
+
+            Ok(())
+        }
+
+        // 以 sender 的身份将存证转移给 dist
+        fn do_transfer_claim(sender: T::AccountId, proof: Vec<u8>, dist: T::AccountId) -> DispatchResult {
+            // 验证签名是否存在，不存在则返回无该存证错误
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            // 存在争议或被冻结的存证不能变更
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            if let Some(info) = CoSignedClaims::<T>::get(&proof) {
+                // 多方共同签署的存证确认后才能转移
+                ensure!(info.status == ClaimStatus::Finalized, Error::<T>::ClaimNotFinalized);
+                // 不能转移给自己，共同所有者也不会为此记录批准
+                ensure!(dist != claim.owner, Error::<T>::TransferToSelf);
+                // 目的地址需要事先同意接收存证持有人的存证
+                ensure!(
+                    TransferApprovals::<T>::get(&dist, &claim.owner),
+                    Error::<T>::TransferNotApproved
+                );
+                // 门限数量的共同所有者同意后才转移
+                if !Self::approve_action(&sender, &proof, &info, ClaimAction::Transfer(dist.clone()))? {
+                    return Ok(());
+                }
+            } else {
+                // 验证请求发送人是否存证所有者，不是则报错
+                ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+                ensure!(dist != sender, Error::<T>::TransferToSelf);
+                // 直接转移需要目的地址事先同意接收发送人的存证，否则应使用 offer_claim
+                ensure!(
+                    TransferApprovals::<T>::get(&dist, &sender),
+                    Error::<T>::TransferNotApproved
+                );
+            }
+            // 转移存证
+            Self::do_transfer(claim, dist, proof)
+        }
+
+        // 将存证转移给 dist：押金随存证一起转移，并追加所有权历史
+        fn do_transfer(
+            claim: ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+            dist: T::AccountId,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = claim.owner;
+            // 不能转移给自己，否则会在所有权历史中留下多余的记录
+            ensure!(dist != sender, Error::<T>::TransferToSelf);
+            // 验证目的地址拥有的存证数量是否已达上限
+            let mut dist_owned = ClaimsByOwner::<T>::get(&dist);
+            ensure!(
+                dist_owned.len() < T::MaxClaimsPerAccount::get() as usize,
+                Error::<T>::TooManyClaims
+            );
+            // 代付的押金仍由代付账户质押，否则押金随存证一起转移到目的地址
+            let deposit = if ClaimDepositors::<T>::contains_key(&proof) {
+                claim.deposit
+            } else {
+                let remaining = T::Currency::repatriate_reserved(
+                    &sender,
+                    &dist,
+                    claim.deposit,
+                    BalanceStatus::Reserved,
+                )?;
+                claim.deposit.saturating_sub(remaining)
+            };
+            // 将存证插入目的地址，使用方法获取当前块
+            let current_block = <frame_system::Module<T>>::block_number();
+            Proofs::<T>::insert(&proof, ClaimInfo {
+                owner: dist.clone(),
+                block_number: current_block,
+                moment: Self::now(),
+                created_at: claim.created_at,
+                deposit,
+                expiry: claim.expiry,
+            });
+            // 追加所有权历史
+            Self::record_owner(&proof, &dist, current_block);
+            // 更新所有者索引
+            Self::remove_from_owner(&sender, &proof);
+            dist_owned.push(proof.clone());
+            ClaimsByOwner::<T>::insert(&dist, dist_owned);
+            // 之前发起的转移失效
+            PendingTransfers::<T>::remove(&proof);
+            // 转移后存证归目的地址单独所有
+            CoSignedClaims::<T>::remove(&proof);
+            PendingActions::<T>::remove(&proof);
+            // 触发转移事件
+            Self::deposit_event(Event::ClaimTransferred(sender, dist, proof));
+
+            Ok(())
+        }
+
+        // 存证是否存在且至少有 AttestationThreshold 个仍然登记的公证人证明
+        pub fn is_verified(proof: &[u8]) -> bool {
+            Proofs::<T>::contains_key(proof) &&
+                Attestations::<T>::get(proof).iter().filter(|notary| Notaries::<T>::get(notary)).count()
+                    >= T::AttestationThreshold::get() as usize
+        }
+
+        // 存证承诺：对 (存证, 盐值, 所有者) 编码后哈希，编码带有长度前缀，不同的拆分不会得到相同的承诺
+        pub fn commitment_of(proof: &[u8], salt: &[u8], owner: &T::AccountId) -> T::Hash {
+            T::Hashing::hash_of(&(proof, salt, owner))
+        }
+
+        // owner 签名的 create_claim_for 内容
+        // 包含前缀 DELEGATED_CLAIM_CONTEXT 和创世区块哈希，签名不能用于其他调用或其他链
+        pub fn delegated_claim_payload(
+            proof: &[u8],
+            expiry: Option<T::BlockNumber>,
+            nonce: u64,
+            deadline: T::BlockNumber,
+        ) -> Vec<u8> {
+            let genesis_hash = <frame_system::Module<T>>::block_hash(T::BlockNumber::zero());
+            (DELEGATED_CLAIM_CONTEXT, genesis_hash, proof, expiry, nonce, deadline).encode()
+        }
+
+        // 验证存证长度、是否已存在以及 owner 拥有的存证数量
+        fn ensure_claimable(owner: &T::AccountId, proof: &[u8]) -> DispatchResult {
+            // 存证限制长度
+            ensure!(proof.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+            // 验证存证是否已经存在，存在则返回Proof已存在错误
+            ensure!(!Proofs::<T>::contains_key(proof), Error::<T>::ProofAlreadyClaimed);
+            // 验证账户拥有的存证数量是否已达上限
+            ensure!(
+                ClaimsByOwner::<T>::decode_len(owner).unwrap_or(0) < T::MaxClaimsPerAccount::get() as usize,
+                Error::<T>::TooManyClaims
+            );
+            Ok(())
+        }
+
+        // 插入存证，由 owner 质押押金
+        fn insert_claim(
+            owner: &T::AccountId,
+            proof: Vec<u8>,
+            block_number: T::BlockNumber,
+            moment: u64,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            Self::insert_claim_paid_by(owner, owner, proof, block_number, moment, expiry)
+        }
+
+        // 插入存证：由 payer 质押押金，登记过期时间、所有权历史和所有者索引
+        fn insert_claim_paid_by(
+            owner: &T::AccountId,
+            payer: &T::AccountId,
+            proof: Vec<u8>,
+            block_number: T::BlockNumber,
+            moment: u64,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            // 验证存证能否创建
+            Self::ensure_claimable(owner, &proof)?;
+            if let Some(expiry) = expiry {
+                Self::ensure_expiry_slot(expiry)?;
+            }
+            let mut owned = ClaimsByOwner::<T>::get(owner);
+            // 按存证长度质押押金
+            let deposit = Self::claim_deposit(&proof);
+            T::Currency::reserve(payer, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+            if payer != owner {
+                ClaimDepositors::<T>::insert(&proof, payer);
+            }
+            // 插入存证，同时记录押金，参数调整后也能按原金额退还
+            Proofs::<T>::insert(&proof, ClaimInfo {
+                owner: owner.clone(),
+                block_number,
+                moment,
+                created_at: moment,
+                deposit,
+                expiry,
+            });
+            // 登记过期时间，到期后自动清理
+            if let Some(expiry) = expiry {
+                ClaimExpiries::<T>::append(expiry, &proof);
+            }
+            // 记录所有权历史的第一条：创建人
+            ClaimHistory::<T>::insert(&proof, vec![(owner.clone(), block_number)]);
+            // 加入所有者索引
+            owned.push(proof.clone());
+            ClaimsByOwner::<T>::insert(owner, owned);
+            // 触发相应事件
+            Self::deposit_event(Event::ClaimCreated(owner.clone(), proof, moment));
+
+            Ok(())
+        }
+
+        // 记录共同所有者对操作的同意，返回是否已达到门限
+        // 其他共同所有者已同意的操作等待期间拒绝不同的操作，直到该操作执行或存证被强制处理
+        fn approve_action(
+            who: &T::AccountId,
+            proof: &[u8],
+            info: &CoSignInfo<T::AccountId>,
+            action: ClaimAction<T::AccountId, T::BlockNumber>,
+        ) -> Result<bool, DispatchError> {
+            ensure!(info.co_owners.contains(who), Error::<T>::NotCoOwner);
+            let mut approvals = match PendingActions::<T>::get(proof) {
+                Some((pending, approvals)) if pending == action => approvals,
+                // 只有独自同意了等待中操作的共同所有者可以取代它
+                Some((_, approvals)) => {
+                    ensure!(approvals.iter().all(|approver| approver == who), Error::<T>::ActionPending);
+                    Vec::new()
+                }
+                None => Vec::new(),
+            };
+            ensure!(!approvals.contains(who), Error::<T>::AlreadyApproved);
+            approvals.push(who.clone());
+            // 达到门限时由调用方执行操作，等待中的操作随存证一起移除
+            if approvals.len() as u32 >= info.threshold {
+                return Ok(true);
+            }
+            PendingActions::<T>::insert(proof, (action.clone(), approvals));
+            Self::deposit_event(Event::ClaimActionApproved(who.clone(), proof.to_vec(), action));
+            Ok(false)
+        }
+
+        // 插入以带算法标记的摘要为键的存证，并记录所用的哈希算法
+        fn insert_hashed_claim(
+            owner: &T::AccountId,
+            algorithm: HashAlgorithm,
+            digest: [u8; 32],
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            // 存证限制有效期
+            Self::ensure_valid_expiry(expiry)?;
+            let current_block = <frame_system::Module<T>>::block_number();
+            let proof = algorithm.tagged(&digest);
+            Self::insert_claim(owner, proof.clone(), current_block, Self::now(), expiry)?;
+            ProofAlgorithms::<T>::insert(proof, algorithm);
+            Ok(())
+        }
+
+        // 描述信息的各字段不能超过对应的长度上限
+        fn ensure_valid_metadata(metadata: &ClaimMetadata) -> DispatchResult {
+            ensure!(
+                metadata.title.len() <= T::MaxTitleLength::get() as usize
+                    && metadata.mime_type.len() <= T::MaxMimeTypeLength::get() as usize
+                    && metadata.uri.len() <= T::MaxUriLength::get() as usize
+                    && metadata.tags.len() <= T::MaxTags::get() as usize
+                    && metadata.tags.iter().all(|tag| tag.len() <= T::MaxTagLength::get() as usize),
+                Error::<T>::BadMetadata
+            );
+            Ok(())
+        }
+
+        // 过期区块必须在未来，且不超过最长有效期
+        fn ensure_valid_expiry(expiry: Option<T::BlockNumber>) -> DispatchResult {
+            if let Some(expiry) = expiry {
+                let now = <frame_system::Module<T>>::block_number();
+                ensure!(
+                    expiry > now && expiry - now <= T::MaxClaimDuration::get(),
+                    Error::<T>::InvalidExpiry
+                );
+            }
+            Ok(())
+        }
+
+        // 移除存证及相关索引，并退还所有者的押金
+        fn remove_claim(
+            proof: &[u8],
+            claim: &ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        ) {
+            let holder = Self::deposit_holder(proof, &claim.owner);
+            Proofs::<T>::remove(proof);
+            ClaimHistory::<T>::remove(proof);
+            ClaimDepositors::<T>::remove(proof);
+            ProofAlgorithms::<T>::remove(proof);
+            PendingTransfers::<T>::remove(proof);
+            CoSignedClaims::<T>::remove(proof);
+            PendingActions::<T>::remove(proof);
+            ClaimMetadataOf::<T>::remove(proof);
+            Attestations::<T>::remove(proof);
+            FrozenClaims::<T>::remove(proof);
+            // 存证被移除时撤销争议并退还争议人的保证金
+            if let Some(dispute) = Disputes::<T>::take(proof) {
+                T::Currency::unreserve(&dispute.challenger, dispute.bond);
+            }
+            Self::remove_from_owner(&claim.owner, proof);
+            if let Some(expiry) = claim.expiry {
+                Self::remove_from_expiries(expiry, proof);
+            }
+            T::Currency::unreserve(&holder, claim.deposit);
+        }
+
+        // 描述信息所需押金：每字节押金 * 编码后的长度
+        pub fn metadata_deposit(metadata: &ClaimMetadata) -> BalanceOf<T> {
+            let bytes: BalanceOf<T> = (metadata.encoded_size() as u32).into();
+            T::ClaimDepositPerByte::get().saturating_mul(bytes)
+        }
+
+        // 替换存证的描述信息，向所有者补充质押新描述信息的押金，并退还旧描述信息的押金
+        // 由交易发送方代付的押金改为全部由所有者质押
+        fn update_metadata(proof: &[u8], metadata: Option<ClaimMetadata>) -> DispatchResult {
+            let mut claim = Proofs::<T>::get(proof).ok_or(Error::<T>::NoSuchProof)?;
+            let old = ClaimMetadataOf::<T>::get(proof)
+                .map_or_else(Zero::zero, |metadata| Self::metadata_deposit(&metadata));
+            let new = metadata.as_ref().map_or_else(Zero::zero, Self::metadata_deposit);
+            let deposit = claim.deposit.saturating_sub(old).saturating_add(new);
+
+            match ClaimDepositors::<T>::get(proof) {
+                Some(payer) => {
+                    T::Currency::reserve(&claim.owner, deposit)
+                        .map_err(|_| Error::<T>::InsufficientBalance)?;
+                    T::Currency::unreserve(&payer, claim.deposit);
+                    ClaimDepositors::<T>::remove(proof);
+                }
+                None if deposit > claim.deposit => {
+                    T::Currency::reserve(&claim.owner, deposit - claim.deposit)
+                        .map_err(|_| Error::<T>::InsufficientBalance)?;
+                }
+                None => {
+                    T::Currency::unreserve(&claim.owner, claim.deposit - deposit);
+                }
+            }
+            claim.deposit = deposit;
+            Proofs::<T>::insert(proof, claim);
+
+            match metadata {
+                Some(metadata) => ClaimMetadataOf::<T>::insert(proof, metadata),
+                None => ClaimMetadataOf::<T>::remove(proof),
+            }
+            Ok(())
+        }
+
+        // 质押存证押金的账户：代付押金的账户，否则为所有者
+        fn deposit_holder(proof: &[u8], owner: &T::AccountId) -> T::AccountId {
+            ClaimDepositors::<T>::get(proof).unwrap_or_else(|| owner.clone())
+        }
+
+        // 存证在无法移除期间已经过期时，安排在之后第一个未满的区块移除
+        fn reschedule_if_expired(proof: &[u8], expiry: Option<T::BlockNumber>) {
+            let current_block = <frame_system::Module<T>>::block_number();
+            if let Some(expiry) = expiry {
+                if expiry <= current_block {
+                    let mut at = current_block + One::one();
+                    while Self::ensure_expiry_slot(at).is_err() {
+                        at += One::one();
+                    }
+                    ClaimExpiries::<T>::append(at, proof);
+                }
+            }
+        }
+
+        // 验证区块 expiry 过期的存证数量未达 MaxExpiriesPerBlock
+        fn ensure_expiry_slot(expiry: T::BlockNumber) -> DispatchResult {
+            ensure!(
+                ClaimExpiries::<T>::decode_len(expiry).unwrap_or(0) < T::MaxExpiriesPerBlock::get() as usize,
+                Error::<T>::ExpiryBlockFull
+            );
+            Ok(())
+        }
+
+        // 从过期索引中移除存证
+        fn remove_from_expiries(expiry: T::BlockNumber, proof: &[u8]) {
+            ClaimExpiries::<T>::mutate_exists(expiry, |maybe_due| {
+                if let Some(due) = maybe_due {
+                    due.retain(|p| p.as_slice() != proof);
+                    if due.is_empty() {
+                        *maybe_due = None;
+                    }
+                }
+            });
+        }
+
+        // 追加存证的所有权历史，超出 MaxHistoryLength 时丢弃创建记录之后最早的记录
+        fn record_owner(proof: &[u8], owner: &T::AccountId, block_number: T::BlockNumber) {
+            let mut history = ClaimHistory::<T>::get(proof);
+            history.push((owner.clone(), block_number));
+            let excess = history.len().saturating_sub(T::MaxHistoryLength::get() as usize);
+            history.drain(1 .. 1 + excess);
+            ClaimHistory::<T>::insert(proof, history);
+        }
+
+        // 从所有者索引中移除存证
+        fn remove_from_owner(owner: &T::AccountId, proof: &[u8]) {
+            ClaimsByOwner::<T>::mutate_exists(owner, |maybe_owned| {
+                if let Some(owned) = maybe_owned {
+                    owned.retain(|p| p.as_slice() != proof);
+                    if owned.is_empty() {
+                        *maybe_owned = None;
+                    }
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// 存证模块的存储迁移
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
+use codec::Decode;
+use frame_support::storage::{unhashed, StoragePrefixedMap};
+use sp_std::vec::Vec;
+
+use crate::{
+    ClaimHistory, ClaimInfo, ClaimsByOwner, Config, Pallet, Proofs, Releases, StorageVersion,
+};
+
+// V1_0_0 版本的存证结构 (所有者, 区块高度)
+pub type ClaimV1<AccountId, BlockNumber> = (AccountId, BlockNumber);
+
+// pre_migrate_to_v2 记录旧存证数量的位置，供 post_migrate_to_v2 检查
+const LEGACY_CLAIM_COUNT: &[u8] = b":poe:migration:legacy_claim_count";
+
+// 将 V2_0_0 之前保存的存证转换为 ClaimInfo
+// 旧的存证没有押金，也不会过期
+// 时间按照距今的区块数和预期的出块间隔 ExpectedBlockTime 估算
+// 旧的存证转移时会覆盖区块高度，无法得知最初的创建时间，created_at 同样以该区块估算
+// 同时按所有者建立索引，并以所有者作为所有权历史的第一条记录
+pub fn migrate_to_v2<T: Config>() -> Weight {
+    // 已经迁移过则跳过
+    if StorageVersion::<T>::get() != Releases::V1_0_0 {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let current_block = <frame_system::Module<T>>::block_number();
+    let now = Pallet::<T>::now();
+    let moment_at = |block_number: T::BlockNumber| -> u64 {
+        let elapsed: u64 = current_block.saturating_sub(block_number).saturated_into();
+        now.saturating_sub(elapsed.saturating_mul(T::ExpectedBlockTime::get()))
+    };
+
+    let mut translated: Weight = 0;
+    // 转换存证
+    Proofs::<T>::translate::<ClaimV1<T::AccountId, T::BlockNumber>, _>(|proof, (owner, block_number)| {
+        translated += 1;
+        ClaimsByOwner::<T>::append(&owner, &proof);
+        ClaimHistory::<T>::append(&proof, (owner.clone(), block_number));
+        let moment = moment_at(block_number);
+        Some(ClaimInfo {
+            owner,
+            block_number,
+            moment,
+            created_at: moment,
+            deposit: Zero::zero(),
+            expiry: None,
+        })
+    });
+    // 记录新的存储版本
+    StorageVersion::<T>::put(Releases::V2_0_0);
+
+    T::DbWeight::get().reads_writes(translated + 1, translated * 3 + 1)
+}
+
+// 升级前检查所有存证都是旧的结构，并记录存证数量
+pub fn pre_migrate_to_v2<T: Config>() -> Result<(), &'static str> {
+    if StorageVersion::<T>::get() != Releases::V1_0_0 {
+        return Ok(());
+    }
+
+    let prefix = Proofs::<T>::final_prefix();
+    let mut key: Vec<u8> = prefix.to_vec();
+    let mut count: u32 = 0;
+    while let Some(next) = sp_io::storage::next_key(&key) {
+        if !next.starts_with(&prefix) {
+            break;
+        }
+        let raw = unhashed::get_raw(&next).ok_or("claim vanished while iterating")?;
+        <ClaimV1<T::AccountId, T::BlockNumber>>::decode(&mut &raw[..])
+            .map_err(|_| "claim is not in the V1_0_0 layout")?;
+        count += 1;
+        key = next;
+    }
+
+    unhashed::put(LEGACY_CLAIM_COUNT, &count);
+    Ok(())
+}
+
+// 升级后检查存储版本已更新，并且所有旧的存证都已转换
+pub fn post_migrate_to_v2<T: Config>() -> Result<(), &'static str> {
+    if StorageVersion::<T>::get() != Releases::V2_0_0 {
+        return Err("storage version was not bumped to V2_0_0");
+    }
+    // 没有记录说明之前已经迁移过
+    let expected = match unhashed::take::<u32>(LEGACY_CLAIM_COUNT) {
+        Some(expected) => expected,
+        None => return Ok(()),
+    };
+
+    let mut count: u32 = 0;
+    for (proof, claim) in Proofs::<T>::iter() {
+        if !ClaimsByOwner::<T>::get(&claim.owner).contains(&proof) {
+            return Err("migrated claim is not indexed under its owner");
+        }
+        count += 1;
+    }
+    if count != expected {
+        return Err("claims were lost by the migration");
+    }
+
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use crate as pallet_poe;
 use sp_core::H256;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, traits::{GenesisBuild, OnFinalize, OnInitialize}};
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup}, testing::Header,
+	traits::{BlakeTwo256, IdentityLookup}, testing::{Header, TestSignature, UintAuthorityId},
 };
 use frame_system as system;
 
@@ -17,6 +17,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Module, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Module, Call, Storage, Inherent},
 		PoeModule: pallet_poe::{Module, Call, Storage, Event<T>},
 	}
 );
@@ -24,7 +26,7 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 42;
-	pub const StringLimit: u32 = 5;
+	pub const StringLimit: u32 = 33;
 }
 
 // 实现系统模块
@@ -46,19 +48,138 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = SS58Prefix;
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+// 实现余额模块，用于质押押金
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkSigner = TestBenchmarkSigner;
+}
+
+// 在基准测试中以账户4签名
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkSigner<u64, TestSignature> for TestBenchmarkSigner {
+	fn sign(payload: &[u8]) -> (u64, TestSignature) {
+		(4, TestSignature(4, payload.to_vec()))
+	}
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+
+// 实现时间戳模块
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static ClaimDepositBase: u64 = 10;
+	pub const ClaimDepositPerByte: u64 = 1;
+	pub const MaxHistoryLength: u32 = 3;
+	pub const MaxClaimsPerAccount: u32 = 2;
+	pub const MaxClaimDuration: u64 = 10;
+	pub const MaxExpiriesPerBlock: u32 = 1;
+	pub const MaxContentLength: u32 = 64;
+	pub const MaxCoOwners: u32 = 3;
+	pub const MaxTitleLength: u32 = 8;
+	pub const MaxMimeTypeLength: u32 = 16;
+	pub const MaxUriLength: u32 = 16;
+	pub const MaxTags: u32 = 2;
+	pub const MaxTagLength: u32 = 4;
+	pub const ExpectedBlockTime: u64 = 6000;
+	pub const RevealWindow: u64 = 5;
+	pub const MaxNotaries: u32 = 2;
+	pub const AttestationThreshold: u32 = 2;
+	pub const ChallengeBond: u64 = 20;
+	pub const MaxBatchSize: u32 = 2;
+}
+
 impl pallet_poe::Config for Test {
 	type Event = Event;
 	type StringLimit = StringLimit;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxHistoryLength = MaxHistoryLength;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type MaxClaimDuration = MaxClaimDuration;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxContentLength = MaxContentLength;
+	type MaxCoOwners = MaxCoOwners;
+	type MaxTitleLength = MaxTitleLength;
+	type MaxMimeTypeLength = MaxMimeTypeLength;
+	type MaxUriLength = MaxUriLength;
+	type MaxTags = MaxTags;
+	type MaxTagLength = MaxTagLength;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type Signature = TestSignature;
+	type Signer = UintAuthorityId;
+	type RevealWindow = RevealWindow;
+	type MaxNotaries = MaxNotaries;
+	type AttestationThreshold = AttestationThreshold;
+	type ChallengeBond = ChallengeBond;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type MaxBatchSize = MaxBatchSize;
+	type WeightInfo = ();
 }
 
 // 构造一个测试用的环境，并初始化
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
-}
\ No newline at end of file
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	// 初始化账户余额，账户3的余额不足以支付押金
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100), (3, 10)],
+	}.assimilate_storage(&mut t).unwrap();
+	let mut t: sp_io::TestExternalities = t.into();
+	// 创世区块不记录事件，从区块1开始
+	t.execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(6000);
+	});
+	t
+}
+
+// 构造一个在创世时登记了 claims 的测试环境
+pub fn new_test_ext_with_claims(claims: Vec<(Vec<u8>, u64)>) -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100), (3, 10)],
+	}.assimilate_storage(&mut t).unwrap();
+	pallet_poe::GenesisConfig::<Test> {
+		claims,
+	}.assimilate_storage(&mut t).unwrap();
+	t.into()
+}
+
+// 推进到区块n，并执行模块的 on_initialize / on_finalize
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		PoeModule::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		PoeModule::on_initialize(System::block_number());
+	}
+}
@@ -1,5 +1,8 @@
 use crate::{Error, mock::*};
-use frame_support::{assert_ok, assert_noop};
+use codec::Encode;
+use frame_support::{assert_err, assert_ok, assert_noop};
+use sp_core::H256;
+use sp_runtime::{testing::TestSignature, traits::{BlakeTwo256, Hash}};
 use super::*;
 
 // 使用cargo test  进行全部测试文件的测试
@@ -13,29 +16,47 @@ fn claim_should_work() {
         let limit = StringLimit::get() as usize;
 
         // 设置账户是1（AccountId是u64，可以为1）， 将claim传入
-        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
         // 验证这个区块是值的，第一个值是账户1，第二个是区块高度
-        assert_eq!(
-            Proofs::<Test>::get(&claim), 
-            (1, frame_system::Pallet::<Test>::block_number())
-        );
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap(), ClaimInfo {
+            owner: 1,
+            block_number: frame_system::Pallet::<Test>::block_number(),
+            moment: 6000,
+            created_at: 6000,
+            deposit: 13,
+            expiry: None,
+        });
+        // 押金 = 基础押金10 + 每字节1 * 3字节
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(Balances::free_balance(1), 87);
         // 验证超过limit长度的cliam在创建时候的报错
         assert_noop!(
-            PoeModule::create_claim(Origin::signed(1), vec![0u8; limit + 1]),
+            PoeModule::create_claim(Origin::signed(1), vec![0u8; limit + 1], None, None),
             Error::<Test>::BadMetadata
         );
     })
 }
 
+#[test]
+fn claim_failed_when_deposit_not_affordable() {
+    new_test_ext().execute_with(|| {
+        // 账户3余额不足以支付押金
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(3), vec![0,1,2], None, None),
+            Error::<Test>::InsufficientBalance
+        );
+    })
+}
+
 #[test]
 fn claim_failed_when_claim_exist() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
         // 同理了
-        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
         // 判断是否已存在，存在时创建会报错
         assert_noop!(
-            PoeModule::create_claim(Origin::signed(2), claim.clone()),
+            PoeModule::create_claim(Origin::signed(2), claim.clone(), None, None),
             Error::<Test>::ProofAlreadyClaimed
         );
     })
@@ -46,11 +67,27 @@ fn revoke_should_work() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
         // 先创建
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
         // 再销毁
         assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
         // 期望：此时已经不存在了
         assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        // 押金全部退还
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+    })
+}
+
+#[test]
+fn revoke_refunds_recorded_deposit() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+        // 押金参数调整后，仍按创建时记录的金额退还
+        ClaimDepositBase::set(50);
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
     })
 }
 
@@ -59,7 +96,7 @@ fn revoke_failed_when_not_owner() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
         // 创建存证
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
         // 原账户是1，现在请求发送方是2，不能销毁存证，期望是销毁时候返回false
         assert_noop!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()), Error::<Test>::NotProofOwner);
     })
@@ -78,11 +115,70 @@ fn revoke_failed_when_claim_not_exist() {
 fn transfer_should_work() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
-        // 转移存证
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        // 转移存证，转移时间晚于创建时间
+        Timestamp::set_timestamp(12_000);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        // 验证转移情况：更新区块高度和时间，创建时间保持不变
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap(), ClaimInfo {
+            owner: 2,
+            block_number: frame_system::Pallet::<Test>::block_number(),
+            moment: 12_000,
+            created_at: 6000,
+            deposit: 13,
+            expiry: None,
+        });
+        // 押金随存证转移给新的所有者
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(2), 13);
+        assert_eq!(Balances::free_balance(2), 100);
+        // 验证转移事件
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimTransferred(1, 2, claim.clone()))
+        );
+    })
+}
+
+#[test]
+fn transfer_records_history() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        // 在区块5转移存证
+        System::set_block_number(5);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        // 历史中保留了最初的创建区块
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (2, 5)]);
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().block_number, 5);
+        // 销毁存证时一并删除历史
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(ClaimHistory::<Test>::contains_key(&claim), false);
+    })
+}
+
+#[test]
+fn transfer_should_drop_oldest_history() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+        // 历史上限为3条
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(1), 2));
+        System::set_block_number(2);
         assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
-        // 验证转移情况
-        assert_eq!(Proofs::<Test>::get(&claim), (2, frame_system::Pallet::<Test>::block_number()));
+        System::set_block_number(3);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 1));
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (2, 2), (1, 3)]);
+
+        // 历史已满时保留创建记录并丢弃之后最早的记录，创建时间仍保留在存证中
+        System::set_block_number(4);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (1, 3), (2, 4)]);
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().created_at, 6000);
     })
 }
 
@@ -91,12 +187,26 @@ fn transfer_failed_when_not_owner() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
         // 创建存证
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
         // 不是该owner时候无法转移
         assert_noop!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 1), Error::<Test>::NotProofOwner);
     })
 }
 
+#[test]
+fn transfer_failed_when_to_self() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+        assert_ok!(PoeModule::approve_sender(Origin::signed(1), 1));
+
+        // 不能将存证直接转移或提供给自己
+        assert_noop!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 1), Error::<Test>::TransferToSelf);
+        assert_noop!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 1), Error::<Test>::TransferToSelf);
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1)]);
+    })
+}
+
 #[test]
 fn transfer_failed_when_claim_not_exist() {
     new_test_ext().execute_with(|| {
@@ -106,3 +216,1479 @@ fn transfer_failed_when_claim_not_exist() {
     })
 }
 
+#[test]
+fn claims_by_owner_should_follow_claims() {
+    new_test_ext().execute_with(|| {
+        let claim_1 = vec![0,1,2];
+        let claim_2 = vec![3,4];
+        // 创建后加入所有者索引
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_1.clone(), None, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_2.clone(), None, None));
+        assert_eq!(PoeModule::claims_by_owner(1), vec![claim_1.clone(), claim_2.clone()]);
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        // 转移后索引跟随新的所有者
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim_1.clone(), 2));
+        assert_eq!(PoeModule::claims_by_owner(1), vec![claim_2.clone()]);
+        assert_eq!(PoeModule::claims_by_owner(2), vec![claim_1.clone()]);
+        // 销毁后从索引中移除
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim_2.clone()));
+        assert_eq!(ClaimsByOwner::<Test>::contains_key(1), false);
+    })
+}
+
+#[test]
+fn claims_of_should_paginate() {
+    new_test_ext().execute_with(|| {
+        let owned: Vec<Vec<u8>> = (0..25u8).map(|i| vec![i]).collect();
+        ClaimsByOwner::<Test>::insert(1, owned.clone());
+        // 每页20条
+        assert_eq!(PoeModule::claims_of(&1, 0), owned[..20].to_vec());
+        assert_eq!(PoeModule::claims_of(&1, 1), owned[20..].to_vec());
+        assert_eq!(PoeModule::claims_of(&1, 2), Vec::<Vec<u8>>::new());
+    })
+}
+
+#[test]
+fn claim_failed_when_too_many_claims() {
+    new_test_ext().execute_with(|| {
+        // 每个账户最多拥有2个存证
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![0], None, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![1], None, None));
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(1), vec![2], None, None),
+            Error::<Test>::TooManyClaims
+        );
+        // 转移给已达上限的账户也会失败
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![3], None, None));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(1), 2));
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(2), vec![3], 1),
+            Error::<Test>::TooManyClaims
+        );
+    })
+}
+
+#[test]
+fn claim_failed_when_expiry_invalid() {
+    new_test_ext().execute_with(|| {
+        // 过期区块必须在未来
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(1), vec![0], Some(1), None),
+            Error::<Test>::InvalidExpiry
+        );
+        // 且不能超过最长有效期
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(1), vec![0], Some(12), None),
+            Error::<Test>::InvalidExpiry
+        );
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![0], Some(11), None));
+    })
+}
+
+#[test]
+fn expired_claims_should_be_pruned() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+        assert_eq!(PoeModule::claim_expiries(3), vec![claim.clone()]);
+        // 未到期时存证仍然存在
+        run_to_block(2);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        // 到期后存证被清理，押金退还
+        run_to_block(3);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(ClaimExpiries::<Test>::contains_key(3), false);
+        assert_eq!(ClaimsByOwner::<Test>::contains_key(1), false);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimExpired(1, claim.clone()))
+        );
+    })
+}
+
+#[test]
+fn expiry_block_should_be_capped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![0], Some(3), None));
+        // 每个区块最多一个存证过期
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(2), vec![1], Some(3), None),
+            Error::<Test>::ExpiryBlockFull
+        );
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![1], Some(4), None));
+
+        // 续期到已满的区块同样被拒绝，续期到原过期区块则不受影响
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(2), vec![1], Some(3)),
+            Error::<Test>::ExpiryBlockFull
+        );
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), vec![0], Some(3)));
+        assert_eq!(PoeModule::claim_expiries(3), vec![vec![0]]);
+
+        // 裁定后需要重新安排的存证顺延到之后第一个未满的区块
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), vec![0], H256::repeat_byte(1)));
+        run_to_block(3);
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), vec![0], true));
+        assert_eq!(PoeModule::claim_expiries(4), vec![vec![1]]);
+        assert_eq!(PoeModule::claim_expiries(5), vec![vec![0]]);
+
+        run_to_block(5);
+        assert_eq!(Proofs::<Test>::contains_key(vec![0]), false);
+        assert_eq!(Proofs::<Test>::contains_key(vec![1]), false);
+    })
+}
+
+#[test]
+fn renew_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+        // 续期到区块5
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(5)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().expiry, Some(5));
+        assert_eq!(ClaimExpiries::<Test>::contains_key(3), false);
+        assert_eq!(PoeModule::claim_expiries(5), vec![claim.clone()]);
+
+        run_to_block(4);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        // 改为永久有效后不会被清理
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), claim.clone(), None));
+        run_to_block(6);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+    })
+}
+
+#[test]
+fn renew_failed_when_not_owner() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+        // 非所有者不能续期
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(2), claim.clone(), Some(5)),
+            Error::<Test>::NotProofOwner
+        );
+        // 存证不存在
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(1), vec![9], Some(5)),
+            Error::<Test>::NoSuchProof
+        );
+    })
+}
+
+#[test]
+fn renew_failed_when_claim_locked() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+
+        // 存在争议的存证不能通过缩短过期区块来逃避挑战
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(2)),
+            Error::<Test>::ClaimDisputed
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), None),
+            Error::<Test>::ClaimDisputed
+        );
+    })
+}
+
+fn leaf(document: H256) -> H256 {
+    BlakeTwo256::hash_of(&(MERKLE_LEAF_PREFIX, document))
+}
+
+fn hash_pair(a: H256, b: H256) -> H256 {
+    if a <= b {
+        BlakeTwo256::hash_of(&(MERKLE_NODE_PREFIX, a, b))
+    } else {
+        BlakeTwo256::hash_of(&(MERKLE_NODE_PREFIX, b, a))
+    }
+}
+
+// 三个文件的批量：root = node(node(leaf(a), leaf(b)), leaf(c))
+fn batch() -> (H256, [H256; 3]) {
+    let leaves = [
+        BlakeTwo256::hash(&[0]),
+        BlakeTwo256::hash(&[1]),
+        BlakeTwo256::hash(&[2]),
+    ];
+    let root = hash_pair(hash_pair(leaf(leaves[0]), leaf(leaves[1])), leaf(leaves[2]));
+    (root, leaves)
+}
+
+#[test]
+fn anchor_batch_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, _) = batch();
+
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+        assert_eq!(PoeModule::batches(root), Some(BatchInfo {
+            owner: 1,
+            block_number: 1,
+            moment: 6000,
+            created_at: 6000,
+            leaf_count: 3,
+            deposit: 42,
+        }));
+        assert_eq!(Balances::reserved_balance(1), 42);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::BatchAnchored(1, root, 3))
+        );
+
+        assert_noop!(
+            PoeModule::anchor_batch(Origin::signed(2), root, 3),
+            Error::<Test>::BatchAlreadyAnchored
+        );
+        assert_noop!(
+            PoeModule::anchor_batch(Origin::signed(2), H256::repeat_byte(1), 0),
+            Error::<Test>::EmptyBatch
+        );
+    })
+}
+
+#[test]
+fn revoke_batch_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, _) = batch();
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+
+        assert_noop!(
+            PoeModule::revoke_batch(Origin::signed(2), root),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::revoke_batch(Origin::signed(1), root));
+        assert_eq!(PoeModule::batches(root), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_noop!(
+            PoeModule::revoke_batch(Origin::signed(1), root),
+            Error::<Test>::NoSuchBatch
+        );
+    })
+}
+
+#[test]
+fn verify_inclusion_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, [a, b, c]) = batch();
+
+        // 未锚定的默克尔根不包含任何文件
+        assert_eq!(PoeModule::verify_inclusion(root, c, &[hash_pair(leaf(a), leaf(b))]), false);
+
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+        assert_eq!(PoeModule::verify_inclusion(root, a, &[leaf(b), leaf(c)]), true);
+        assert_eq!(PoeModule::verify_inclusion(root, b, &[leaf(a), leaf(c)]), true);
+        assert_eq!(PoeModule::verify_inclusion(root, c, &[hash_pair(leaf(a), leaf(b))]), true);
+
+        assert_eq!(PoeModule::verify_inclusion(root, a, &[leaf(c), leaf(b)]), false);
+        assert_eq!(PoeModule::verify_inclusion(root, H256::repeat_byte(1), &[leaf(b), leaf(c)]), false);
+        // 证明长度超过树的深度
+        assert_eq!(PoeModule::verify_inclusion(root, a, &[leaf(b), leaf(c), leaf(c)]), false);
+        // 中间节点不能冒充文件
+        assert_eq!(PoeModule::verify_inclusion(root, hash_pair(leaf(a), leaf(b)), &[leaf(c)]), false);
+    })
+}
+
+#[test]
+fn claim_from_batch_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, [a, b, c]) = batch();
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+        run_to_block(5);
+
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(2), root, a, vec![leaf(b), leaf(c)]),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(1), root, a, vec![leaf(c), leaf(b)]),
+            Error::<Test>::InvalidMerkleProof
+        );
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(1), root, hash_pair(leaf(a), leaf(b)), vec![leaf(c)]),
+            Error::<Test>::InvalidMerkleProof
+        );
+
+        assert_ok!(PoeModule::claim_from_batch(Origin::signed(1), root, a, vec![leaf(b), leaf(c)]));
+        // 存证沿用锚定时的区块高度
+        assert_eq!(Proofs::<Test>::get(a.as_ref().to_vec()).unwrap(), ClaimInfo {
+            owner: 1,
+            block_number: 1,
+            moment: 6000,
+            created_at: 6000,
+            deposit: 42,
+            expiry: None,
+        });
+        assert_eq!(Balances::reserved_balance(1), 84);
+        assert_eq!(PoeModule::claims_by_owner(1), vec![a.as_ref().to_vec()]);
+
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(1), root, a, vec![leaf(b), leaf(c)]),
+            Error::<Test>::ProofAlreadyClaimed
+        );
+    })
+}
+
+#[test]
+fn hash_and_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        // 内容长于 StringLimit，但不超过 MaxContentLength
+        let content = vec![7u8; 40];
+        let digest = sp_io::hashing::sha2_256(&content);
+        let proof = HashAlgorithm::Sha2_256.tagged(&digest);
+
+        assert_ok!(PoeModule::hash_and_claim(
+            Origin::signed(1), HashAlgorithm::Sha2_256, content.clone(), None
+        ));
+        assert_eq!(Proofs::<Test>::get(&proof).unwrap().owner, 1);
+        assert_eq!(PoeModule::proof_algorithm(&proof), Some(HashAlgorithm::Sha2_256));
+        // 存证键包含1字节的算法标记
+        assert_eq!(Balances::reserved_balance(1), 43);
+
+        // 同一摘要不能以同一算法再次登记
+        assert_noop!(
+            PoeModule::create_hashed_claim(Origin::signed(2), HashAlgorithm::Sha2_256, digest, None),
+            Error::<Test>::ProofAlreadyClaimed
+        );
+        assert_noop!(
+            PoeModule::hash_and_claim(Origin::signed(1), HashAlgorithm::Sha2_256, vec![0u8; 65], None),
+            Error::<Test>::ContentTooLong
+        );
+
+        // 销毁存证时同时移除算法记录
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), proof.clone()));
+        assert_eq!(PoeModule::proof_algorithm(&proof), None);
+    })
+}
+
+#[test]
+fn create_hashed_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        let digest = sp_io::hashing::keccak_256(b"document");
+        let proof = HashAlgorithm::Keccak256.tagged(&digest);
+
+        assert_ok!(PoeModule::create_hashed_claim(
+            Origin::signed(1), HashAlgorithm::Keccak256, digest, Some(3)
+        ));
+        assert_eq!(PoeModule::proof_algorithm(&proof), Some(HashAlgorithm::Keccak256));
+        assert_eq!(PoeModule::claim_expiries(3), vec![proof.clone()]);
+
+        // 转移存证时保留算法记录
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), proof.clone(), 2));
+        assert_eq!(PoeModule::proof_algorithm(&proof), Some(HashAlgorithm::Keccak256));
+
+        // 普通存证没有算法记录
+        assert_eq!(PoeModule::proof_algorithm(vec![0, 1, 2]), None);
+    })
+}
+
+#[test]
+fn hashed_claims_should_be_separated_by_algorithm() {
+    new_test_ext().execute_with(|| {
+        let digest = sp_io::hashing::keccak_256(b"document");
+
+        // 相同的摘要可以以不同算法和普通存证分别登记
+        assert_ok!(PoeModule::create_hashed_claim(Origin::signed(1), HashAlgorithm::Keccak256, digest, None));
+        assert_ok!(PoeModule::create_hashed_claim(Origin::signed(2), HashAlgorithm::Sha2_256, digest, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), digest.to_vec(), None, None));
+
+        assert_eq!(Proofs::<Test>::get(HashAlgorithm::Keccak256.tagged(&digest)).unwrap().owner, 1);
+        assert_eq!(Proofs::<Test>::get(HashAlgorithm::Sha2_256.tagged(&digest)).unwrap().owner, 2);
+        assert_eq!(PoeModule::proof_algorithm(digest.to_vec()), None);
+    })
+}
+
+#[test]
+fn transfer_failed_when_not_approved() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // 未经同意不能直接转移
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2),
+            Error::<Test>::TransferNotApproved
+        );
+
+        // 同意后可以直接转移
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_eq!(PoeModule::is_sender_approved(2, 1), true);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![3], None, None));
+
+        // 撤销同意后不能再直接转移
+        assert_ok!(PoeModule::unapprove_sender(Origin::signed(2), 1));
+        assert_eq!(PoeModule::is_sender_approved(2, 1), false);
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), vec![3], 2),
+            Error::<Test>::TransferNotApproved
+        );
+    })
+}
+
+#[test]
+fn offer_and_accept_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        assert_noop!(
+            PoeModule::offer_claim(Origin::signed(2), claim.clone(), 3),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::pending_transfer(&claim), Some(2));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::TransferOffered(1, 2, claim.clone()))
+        );
+
+        // 只有接收方可以接受
+        assert_noop!(
+            PoeModule::accept_claim(Origin::signed(3), claim.clone()),
+            Error::<Test>::NotTransferRecipient
+        );
+
+        // 接受后存证和押金转移给接收方
+        assert_ok!(PoeModule::accept_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 2);
+        assert_eq!(Balances::reserved_balance(2), 13);
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimTransferred(1, 2, claim.clone()))
+        );
+        assert_noop!(
+            PoeModule::accept_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::NoPendingTransfer
+        );
+    })
+}
+
+#[test]
+fn cancel_offer_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::cancel_offer(Origin::signed(1), claim.clone()),
+            Error::<Test>::NoPendingTransfer
+        );
+
+        assert_ok!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2));
+        // 只有所有者可以取消
+        assert_noop!(
+            PoeModule::cancel_offer(Origin::signed(2), claim.clone()),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::cancel_offer(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::TransferCancelled(1, 2, claim.clone()))
+        );
+        assert_noop!(
+            PoeModule::accept_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::NoPendingTransfer
+        );
+    })
+}
+
+#[test]
+fn offers_should_not_outlive_ownership() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // 存证转移后之前的转移失效
+        assert_ok!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 3));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+
+        // 销毁存证时同时移除待接受的转移
+        assert_ok!(PoeModule::offer_claim(Origin::signed(2), claim.clone(), 3));
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+    })
+}
+
+#[test]
+fn cosigned_claim_should_finalize() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 3], 2, None));
+        // 创建人计为第一个同意
+        assert_eq!(PoeModule::co_signed_claim(&claim), Some(CoSignInfo {
+            co_owners: vec![1, 2, 3],
+            threshold: 2,
+            approvals: vec![1],
+            status: ClaimStatus::Pending,
+        }));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(1), claim.clone()),
+            Error::<Test>::AlreadyApproved
+        );
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(4), claim.clone()),
+            Error::<Test>::NotCoOwner
+        );
+
+        assert_ok!(PoeModule::approve_claim(Origin::signed(3), claim.clone()));
+        assert_eq!(PoeModule::co_signed_claim(&claim).unwrap().status, ClaimStatus::Finalized);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimFinalized(claim.clone()))
+        );
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::AlreadyFinalized
+        );
+
+        // 单一所有者的存证创建后即确认
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![3], None, None));
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(2), vec![3]),
+            Error::<Test>::AlreadyFinalized
+        );
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(2), vec![4]),
+            Error::<Test>::NoSuchProof
+        );
+    })
+}
+
+#[test]
+fn cosigned_claim_failed_when_invalid() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        // 创建人自动加入共同所有者
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 3, 4], 2, None),
+            Error::<Test>::TooManyCoOwners
+        );
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 2], 2, None),
+            Error::<Test>::DuplicateCoOwner
+        );
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 0, None),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![1, 2], 3, None),
+            Error::<Test>::InvalidThreshold
+        );
+
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 1, None));
+        assert_eq!(PoeModule::co_signed_claim(&claim).unwrap().status, ClaimStatus::Finalized);
+    })
+}
+
+#[test]
+fn cosigned_transfer_requires_threshold() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 3], 2, None));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(3), 1));
+
+        // 未确认的存证不能转移
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3),
+            Error::<Test>::ClaimNotFinalized
+        );
+        assert_ok!(PoeModule::approve_claim(Origin::signed(2), claim.clone()));
+
+        // 门限未达到时存证保持不变
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(PoeModule::pending_action(&claim), Some((ClaimAction::Transfer(3), vec![1])));
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3),
+            Error::<Test>::AlreadyApproved
+        );
+
+        // 其他共同所有者不能以不同的操作取代等待中的操作
+        assert_noop!(
+            PoeModule::revoke_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::ActionPending
+        );
+        // 独自同意的共同所有者可以改变主意
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::pending_action(&claim), Some((ClaimAction::Revoke, vec![1])));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 3));
+
+        // 达到门限后存证归目的地址单独所有
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 3);
+        assert_eq!(Balances::reserved_balance(3), 13);
+        assert_eq!(PoeModule::co_signed_claim(&claim), None);
+        assert_eq!(PoeModule::pending_action(&claim), None);
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim.clone()));
+    })
+}
+
+#[test]
+fn cosigned_revoke_requires_threshold() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 2, None));
+
+        // 多方共同签署的存证不能通过 offer_claim 转移
+        assert_noop!(
+            PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2),
+            Error::<Test>::CoSignedClaim
+        );
+        assert_noop!(
+            PoeModule::revoke_claim(Origin::signed(3), claim.clone()),
+            Error::<Test>::NotCoOwner
+        );
+
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimActionApproved(2, claim.clone(), ClaimAction::Revoke))
+        );
+
+        // 达到门限后销毁存证并退还创建人的押金
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(PoeModule::co_signed_claim(&claim), None);
+        assert_eq!(PoeModule::pending_action(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn cosigned_renew_requires_threshold() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 2, None));
+
+        // 创建者不能单独让存证过期
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(3)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().expiry, None);
+        assert_eq!(PoeModule::pending_action(&claim), Some((ClaimAction::Renew(Some(3)), vec![1])));
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(3), claim.clone(), Some(3)),
+            Error::<Test>::NotCoOwner
+        );
+
+        assert_ok!(PoeModule::renew_claim(Origin::signed(2), claim.clone(), Some(3)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().expiry, Some(3));
+        assert_eq!(PoeModule::claim_expiries(3), vec![claim.clone()]);
+        assert_eq!(PoeModule::pending_action(&claim), None);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimRenewed(1, claim.clone(), Some(3)))
+        );
+    })
+}
+
+fn metadata() -> ClaimMetadata {
+    ClaimMetadata {
+        title: b"contract".to_vec(),
+        mime_type: b"application/pdf".to_vec(),
+        uri: b"ipfs://Qm".to_vec(),
+        tags: vec![b"law".to_vec(), b"2021".to_vec()],
+    }
+}
+
+#[test]
+fn claim_with_metadata_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, Some(metadata())));
+        assert_eq!(PoeModule::claim_metadata(&claim), Some(metadata()));
+        // 押金包含编码后的描述信息：13 + 45
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().deposit, 58);
+        assert_eq!(Balances::reserved_balance(1), 58);
+
+        // 余额不足以同时质押存证和描述信息的押金
+        Balances::make_free_balance_be(&2, 20);
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(2), vec![3], None, Some(metadata())),
+            Error::<Test>::InsufficientBalance
+        );
+        Balances::make_free_balance_be(&2, 100);
+
+        // 每个字段都有各自的长度上限
+        let invalid = vec![
+            ClaimMetadata { title: vec![0; 9], ..metadata() },
+            ClaimMetadata { mime_type: vec![0; 17], ..metadata() },
+            ClaimMetadata { uri: vec![0; 17], ..metadata() },
+            ClaimMetadata { tags: vec![vec![0]; 3], ..metadata() },
+            ClaimMetadata { tags: vec![vec![0; 5]], ..metadata() },
+        ];
+        for metadata in invalid {
+            assert_noop!(
+                PoeModule::create_claim(Origin::signed(1), vec![3], None, Some(metadata)),
+                Error::<Test>::BadMetadata
+            );
+        }
+
+        // 描述信息随存证一起转移
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::claim_metadata(&claim), Some(metadata()));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 58);
+    })
+}
+
+#[test]
+fn set_claim_metadata_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_eq!(PoeModule::claim_metadata(&claim), None);
+
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())));
+        assert_eq!(PoeModule::claim_metadata(&claim), Some(metadata()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimMetadataUpdated(1, claim.clone()))
+        );
+        // 补充质押描述信息的押金
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().deposit, 58);
+        assert_eq!(Balances::reserved_balance(1), 58);
+
+        // 描述信息变短时退还差额
+        let short = ClaimMetadata { tags: vec![], ..metadata() };
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(short)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().deposit, 49);
+        assert_eq!(Balances::reserved_balance(1), 49);
+
+        // 余额不足以补充质押
+        Balances::make_free_balance_be(&1, 5);
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())),
+            Error::<Test>::InsufficientBalance
+        );
+        Balances::make_free_balance_be(&1, 100 - 49);
+
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(2), claim.clone(), None),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), vec![3], None),
+            Error::<Test>::NoSuchProof
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(
+                Origin::signed(1), claim.clone(), Some(ClaimMetadata { title: vec![0; 9], ..metadata() })
+            ),
+            Error::<Test>::BadMetadata
+        );
+
+        // None 清除描述信息
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), None));
+        assert_eq!(PoeModule::claim_metadata(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        // 销毁存证时同时移除描述信息
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())));
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::claim_metadata(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+    })
+}
+
+#[test]
+fn migrate_to_v2_should_translate_legacy_claims() {
+    new_test_ext().execute_with(|| {
+        // V1_0_0 版本的存证，创建于区块2和区块4
+        let claim = vec![0,1,2];
+        let other = vec![3,4];
+        frame_support::storage::unhashed::put(
+            &Proofs::<Test>::hashed_key_for(&claim),
+            &(1u64, 2u64),
+        );
+        frame_support::storage::unhashed::put(
+            &Proofs::<Test>::hashed_key_for(&other),
+            &(2u64, 4u64),
+        );
+
+        // 在区块5升级，每个区块6秒
+        run_to_block(5);
+        Timestamp::set_timestamp(30_000);
+        // 升级前检查旧的存证，升级后检查转换结果
+        assert_ok!(migrations::pre_migrate_to_v2::<Test>());
+        assert_eq!(
+            migrations::post_migrate_to_v2::<Test>(),
+            Err("storage version was not bumped to V2_0_0")
+        );
+        migrations::migrate_to_v2::<Test>();
+        assert_ok!(migrations::post_migrate_to_v2::<Test>());
+
+        assert_eq!(Proofs::<Test>::get(&claim), Some(ClaimInfo {
+            owner: 1,
+            block_number: 2,
+            moment: 12_000,
+            created_at: 12_000,
+            deposit: 0,
+            expiry: None,
+        }));
+        assert_eq!(Proofs::<Test>::get(&other).unwrap().moment, 24_000);
+        assert_eq!(StorageVersion::<Test>::get(), Releases::V2_0_0);
+        // 迁移后的存证按所有者建立索引，并保留所有权历史
+        assert_eq!(PoeModule::claims_by_owner(1), vec![claim.clone()]);
+        assert_eq!(PoeModule::claims_by_owner(2), vec![other.clone()]);
+        assert_eq!(PoeModule::claim_history(&other), vec![(2, 4)]);
+
+        // 迁移只执行一次
+        Timestamp::set_timestamp(36_000);
+        migrations::migrate_to_v2::<Test>();
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().moment, 12_000);
+
+        // 旧的存证没有押金
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(Proofs::<Test>::get(&claim), None);
+        assert_eq!(Balances::free_balance(1), 100);
+    })
+}
+
+fn owner_signature(owner: u64, proof: &[u8], expiry: Option<u64>, nonce: u64, deadline: u64) -> TestSignature {
+    TestSignature(owner, PoeModule::delegated_claim_payload(proof, expiry, nonce, deadline))
+}
+
+#[test]
+fn create_claim_for_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let signature = owner_signature(3, &claim, None, 0, 5);
+
+        assert_ok!(PoeModule::create_claim_for(Origin::signed(1), 3, claim.clone(), None, 5, signature.clone()));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 3);
+        assert_eq!(PoeModule::nonce_of(3), 1);
+        assert_eq!(PoeModule::claims_by_owner(3), vec![claim.clone()]);
+        // 交易发送方支付押金，押金质押在交易发送方账户
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(PoeModule::claim_depositor(&claim), Some(1));
+
+        // 同一个签名不能重放
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim.clone()));
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(PoeModule::claim_depositor(&claim), None);
+        assert_noop!(
+            PoeModule::create_claim_for(Origin::signed(1), 3, claim.clone(), None, 5, signature),
+            Error::<Test>::InvalidSignature
+        );
+        assert_ok!(PoeModule::create_claim_for(
+            Origin::signed(1), 3, claim.clone(), None, 5, owner_signature(3, &claim, None, 1, 5)
+        ));
+    })
+}
+
+#[test]
+fn create_claim_for_deposit_should_stay_with_relayer() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+
+        // 账户4没有余额，由交易发送方质押押金
+        assert_ok!(PoeModule::create_claim_for(
+            Origin::signed(1), 4, claim.clone(), None, 5, owner_signature(4, &claim, None, 0, 5)
+        ));
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        // 存证转移后押金仍由交易发送方质押
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 4));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(4), claim.clone(), 2));
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(PoeModule::claim_depositor(&claim), Some(1));
+
+        // 存证移除时押金退还给交易发送方
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+
+        // 所有者设置描述信息时，改为由所有者质押全部押金
+        assert_ok!(PoeModule::create_claim_for(
+            Origin::signed(1), 2, claim.clone(), None, 5, owner_signature(2, &claim, None, 0, 5)
+        ));
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(2), claim.clone(), Some(metadata())));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 58);
+        assert_eq!(PoeModule::claim_depositor(&claim), None);
+    })
+}
+
+#[test]
+fn create_claim_for_failed_when_signature_invalid() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+
+        // 签名内容必须与调用参数一致
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), None, 5, owner_signature(2, &claim, None, 0, 5)
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), Some(4), 5, owner_signature(3, &claim, None, 0, 5)
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, vec![9], None, 5, owner_signature(3, &claim, None, 0, 5)
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // 签名内容必须包含前缀和创世区块哈希
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), None, 5,
+                TestSignature(3, (&claim[..], None::<u64>, 0u64, 5u64).encode())
+            ),
+            Error::<Test>::InvalidSignature
+        );
+
+        // 签名已过截止区块
+        run_to_block(6);
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), None, 5, owner_signature(3, &claim, None, 0, 5)
+            ),
+            Error::<Test>::SignatureExpired
+        );
+        assert_eq!(PoeModule::nonce_of(3), 0);
+    })
+}
+
+#[test]
+fn commit_and_reveal_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let salt = vec![7,7];
+        let commitment = PoeModule::commitment_of(&claim, &salt, &1);
+        assert_eq!(commitment, BlakeTwo256::hash_of(&(claim.clone(), salt.clone(), 1u64)));
+        // 承诺对各部分分别编码，移动存证与盐值的边界会得到不同的承诺
+        assert_ne!(commitment, PoeModule::commitment_of(&[0,1], &[2,7,7], &1));
+
+        // 承诺不公开存证
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+        assert_eq!(PoeModule::commitments(commitment).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(1), 42);
+        assert!(!Proofs::<Test>::contains_key(&claim));
+        assert_noop!(
+            PoeModule::commit_claim(Origin::signed(2), commitment),
+            Error::<Test>::CommitmentAlreadyExists
+        );
+
+        // 揭示后存证的区块高度沿用提交承诺时的高度
+        run_to_block(4);
+        assert_ok!(PoeModule::reveal_claim(Origin::signed(1), claim.clone(), salt));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().block_number, 1);
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(PoeModule::commitments(commitment), None);
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimRevealed(1, commitment, claim))
+        );
+    })
+}
+
+#[test]
+fn reveal_claim_failed_when_commitment_invalid() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let salt = vec![7,7];
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), PoeModule::commitment_of(&claim, &salt, &1)));
+
+        // 盐值或发送方不符时找不到承诺
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(1), claim.clone(), vec![8]),
+            Error::<Test>::NoSuchCommitment
+        );
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(2), claim.clone(), salt.clone()),
+            Error::<Test>::NoSuchCommitment
+        );
+
+        // 超过揭示期限后不能揭示
+        run_to_block(7);
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(1), claim, salt),
+            Error::<Test>::RevealWindowClosed
+        );
+    })
+}
+
+#[test]
+fn reveal_claim_failed_when_commitment_copied() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let salt = vec![7,7];
+        // 账户 1 抢先提交了账户 2 将要提交的承诺
+        let commitment = PoeModule::commitment_of(&claim, &salt, &2);
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![9], None, None));
+
+        // 承诺只对提交它的账户有效
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(2), claim.clone(), salt.clone()),
+            Error::<Test>::NoSuchCommitment
+        );
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(1), claim.clone(), salt),
+            Error::<Test>::NoSuchCommitment
+        );
+        assert_eq!(Balances::reserved_balance(1), 42);
+        assert_eq!(Balances::reserved_balance(2), 11);
+
+        // 押金退还给提交承诺的账户
+        run_to_block(7);
+        assert_ok!(PoeModule::reap_commitment(Origin::signed(2), commitment));
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn reap_commitment_should_work() {
+    new_test_ext().execute_with(|| {
+        let commitment = PoeModule::commitment_of(&[0,1,2], &[7,7], &1);
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+
+        // 揭示期限内不能清理
+        run_to_block(6);
+        assert_noop!(
+            PoeModule::reap_commitment(Origin::signed(2), commitment),
+            Error::<Test>::RevealWindowOpen
+        );
+
+        // 任何账户都可以清理，押金退还给所有者
+        run_to_block(7);
+        assert_ok!(PoeModule::reap_commitment(Origin::signed(2), commitment));
+        assert_eq!(PoeModule::commitments(commitment), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::CommitmentReaped(1, commitment))
+        );
+        assert_noop!(
+            PoeModule::reap_commitment(Origin::signed(2), commitment),
+            Error::<Test>::NoSuchCommitment
+        );
+    })
+}
+
+#[test]
+fn attest_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // 只有 root 可以登记公证人
+        assert_noop!(PoeModule::add_notary(Origin::signed(1), 2), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(PoeModule::add_notary(Origin::root(), 2));
+        assert_ok!(PoeModule::add_notary(Origin::root(), 3));
+        assert_eq!(PoeModule::notary_count(), 2);
+        assert_noop!(PoeModule::add_notary(Origin::root(), 2), Error::<Test>::AlreadyNotary);
+        // 公证人数量已达上限
+        assert_noop!(PoeModule::add_notary(Origin::root(), 4), Error::<Test>::TooManyNotaries);
+
+        // 只有公证人可以证明
+        assert_noop!(PoeModule::attest_claim(Origin::signed(1), claim.clone()), Error::<Test>::NotNotary);
+        assert_noop!(PoeModule::attest_claim(Origin::signed(2), vec![9]), Error::<Test>::NoSuchProof);
+
+        assert_ok!(PoeModule::attest_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimAttested(2, claim.clone()))
+        );
+        assert!(!PoeModule::is_verified(&claim));
+        // 同一个公证人只能证明一次
+        assert_noop!(PoeModule::attest_claim(Origin::signed(2), claim.clone()), Error::<Test>::AlreadyAttested);
+
+        // 达到门限后存证被视为已验证
+        assert_ok!(PoeModule::attest_claim(Origin::signed(3), claim.clone()));
+        assert_eq!(PoeModule::attestations(&claim), vec![2, 3]);
+        assert!(PoeModule::is_verified(&claim));
+
+        // 移除的公证人不再计入验证
+        assert_ok!(PoeModule::remove_notary(Origin::root(), 3));
+        assert_noop!(PoeModule::remove_notary(Origin::root(), 3), Error::<Test>::NotNotary);
+        assert_eq!(PoeModule::notary_count(), 1);
+        assert!(!PoeModule::is_verified(&claim));
+
+        // 销毁存证同时清除证明
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::attestations(&claim), Vec::<u64>::new());
+    })
+}
+
+#[test]
+fn challenge_claim_should_lock_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let evidence = H256::repeat_byte(1);
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // 所有者不能对自己的存证提出争议
+        assert_noop!(
+            PoeModule::challenge_claim(Origin::signed(1), claim.clone(), evidence),
+            Error::<Test>::ChallengerIsOwner
+        );
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), evidence));
+        assert_eq!(Balances::reserved_balance(2), 20);
+        assert_eq!(PoeModule::dispute(&claim).unwrap().challenger, 2);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimChallenged(2, claim.clone(), evidence))
+        );
+        // 同一时间只能有一个争议
+        assert_noop!(
+            PoeModule::challenge_claim(Origin::signed(3), claim.clone(), evidence),
+            Error::<Test>::ClaimDisputed
+        );
+
+        // 争议期间存证不能转移或销毁
+        assert_ok!(PoeModule::approve_sender(Origin::signed(3), 1));
+        assert_noop!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()), Error::<Test>::ClaimDisputed);
+        assert_noop!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3), Error::<Test>::ClaimDisputed);
+        assert_noop!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 3), Error::<Test>::ClaimDisputed);
+    })
+}
+
+#[test]
+fn resolve_dispute_should_uphold_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        // 只有 root 可以裁定
+        assert_noop!(
+            PoeModule::resolve_dispute(Origin::signed(1), claim.clone(), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PoeModule::resolve_dispute(Origin::root(), vec![9], true),
+            Error::<Test>::NotDisputed
+        );
+
+        // 维持原所有者，罚没争议人的保证金
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), true));
+        assert_eq!(PoeModule::dispute(&claim), None);
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 80);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::DisputeUpheld(1, 2, claim.clone()))
+        );
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim));
+    })
+}
+
+#[test]
+fn resolve_dispute_should_reassign_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        // 改判给争议人，罚没原所有者的押金，区块高度保持不变
+        run_to_block(3);
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), false));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap(), ClaimInfo {
+            owner: 2,
+            block_number: 1,
+            moment: 6000,
+            created_at: 6000,
+            deposit: 20,
+            expiry: None,
+        });
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(2), 20);
+        assert_eq!(PoeModule::claims_by_owner(1), Vec::<Vec<u8>>::new());
+        assert_eq!(PoeModule::claims_by_owner(2), vec![claim.clone()]);
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (2, 3)]);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimReassigned(1, 2, claim.clone()))
+        );
+
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim));
+        assert_eq!(Balances::free_balance(2), 100);
+    })
+}
+
+#[test]
+fn disputed_claims_should_not_expire() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        // 存在争议期间存证不会过期
+        run_to_block(3);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        assert_eq!(Balances::reserved_balance(2), 20);
+
+        // 裁定后在下一个区块移除
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), true));
+        assert_eq!(PoeModule::claim_expiries(4), vec![claim.clone()]);
+        run_to_block(4);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn resolve_dispute_should_void_claim_when_challenger_full() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![5], None, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![6], None, None));
+
+        // 争议人无法再拥有存证，移除存证并退还保证金
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), false));
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(PoeModule::dispute(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(2), 22);
+        assert_eq!(Balances::free_balance(2), 78);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimVoided(1, 2, claim))
+        );
+    })
+}
+
+#[test]
+fn force_transfer_should_drop_dispute() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        assert_ok!(PoeModule::force_transfer(Origin::root(), claim.clone(), 3));
+        assert_eq!(PoeModule::dispute(&claim), None);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 100);
+
+        // 新的所有者可以自由处置存证
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim));
+    })
+}
+
+#[test]
+fn freeze_claim_should_lock_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // 只有 ForceOrigin 可以冻结
+        assert_noop!(PoeModule::freeze_claim(Origin::signed(1), claim.clone()), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(PoeModule::freeze_claim(Origin::root(), vec![9]), Error::<Test>::NoSuchProof);
+        assert_ok!(PoeModule::freeze_claim(Origin::root(), claim.clone()));
+        assert!(PoeModule::is_frozen(&claim));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimFrozen(claim.clone()))
+        );
+        assert_noop!(PoeModule::freeze_claim(Origin::root(), claim.clone()), Error::<Test>::AlreadyFrozen);
+
+        // 冻结后所有者不能转移或销毁
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_noop!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()), Error::<Test>::ClaimIsFrozen);
+        assert_noop!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2), Error::<Test>::ClaimIsFrozen);
+        assert_noop!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2), Error::<Test>::ClaimIsFrozen);
+
+        // 解冻后恢复正常
+        assert_ok!(PoeModule::thaw_claim(Origin::root(), claim.clone()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimThawed(claim.clone()))
+        );
+        assert_noop!(PoeModule::thaw_claim(Origin::root(), claim.clone()), Error::<Test>::NotFrozen);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+    })
+}
+
+#[test]
+fn frozen_claims_should_not_expire() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(5), None));
+        assert_ok!(PoeModule::freeze_claim(Origin::root(), claim.clone()));
+
+        // 所有者不能缩短被冻结存证的过期区块，也不能修改描述信息
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(2)),
+            Error::<Test>::ClaimIsFrozen
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())),
+            Error::<Test>::ClaimIsFrozen
+        );
+
+        // 被冻结的存证不会过期
+        run_to_block(5);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        // 解冻后在下一个区块移除
+        assert_ok!(PoeModule::thaw_claim(Origin::root(), claim.clone()));
+        assert_eq!(PoeModule::claim_expiries(6), vec![claim.clone()]);
+        run_to_block(6);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn force_revoke_and_transfer_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::freeze_claim(Origin::root(), claim.clone()));
+
+        // 强制转移不需要接收方同意
+        assert_noop!(
+            PoeModule::force_transfer(Origin::signed(1), claim.clone(), 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::force_transfer(Origin::root(), claim.clone(), 2));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 2);
+        assert_eq!(Balances::reserved_balance(2), 13);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimForceTransferred(1, 2, claim.clone()))
+        );
+
+        // 强制销毁退还所有者的押金
+        assert_noop!(PoeModule::force_revoke(Origin::root(), vec![9]), Error::<Test>::NoSuchProof);
+        assert_ok!(PoeModule::force_revoke(Origin::root(), claim.clone()));
+        assert!(!Proofs::<Test>::contains_key(&claim));
+        assert!(!PoeModule::is_frozen(&claim));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimForceRevoked(2, claim))
+        );
+    })
+}
+
+#[test]
+fn create_claims_should_work() {
+    new_test_ext().execute_with(|| {
+        let claims = vec![vec![0,1], vec![0,2]];
+
+        // 批量数量不能超过 MaxBatchSize
+        assert_noop!(
+            PoeModule::create_claims(Origin::signed(1), vec![vec![0], vec![1], vec![2]]),
+            Error::<Test>::BatchTooLarge
+        );
+
+        assert_ok!(PoeModule::create_claims(Origin::signed(1), claims.clone()));
+        assert_eq!(PoeModule::claims_by_owner(1), claims);
+        assert_eq!(Balances::reserved_balance(1), 24);
+    })
+}
+
+#[test]
+fn create_claims_failed_should_roll_back() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![0,2], None, None));
+
+        // 第二项已被创建，整个批量回滚
+        assert_err!(
+            PoeModule::create_claims(Origin::signed(1), vec![vec![0,1], vec![0,2]]),
+            Error::<Test>::BatchItemFailed
+        );
+        assert!(!Proofs::<Test>::contains_key(&vec![0,1]));
+        assert_eq!(PoeModule::claims_by_owner(1), Vec::<Vec<u8>>::new());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::BatchInterrupted(
+                1, Error::<Test>::ProofAlreadyClaimed.into()
+            ))
+        );
+    })
+}
+
+#[test]
+fn revoke_and_transfer_claims_should_work() {
+    new_test_ext().execute_with(|| {
+        let claims = vec![vec![0,1], vec![0,2]];
+        assert_ok!(PoeModule::create_claims(Origin::signed(1), claims.clone()));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+
+        // 批量转移
+        assert_err!(
+            PoeModule::transfer_claims(Origin::signed(1), vec![(vec![0,1], 2), (vec![0,2], 3)]),
+            Error::<Test>::BatchItemFailed
+        );
+        assert_eq!(PoeModule::claims_by_owner(1), claims);
+        assert_ok!(PoeModule::transfer_claims(Origin::signed(1), vec![(vec![0,1], 2), (vec![0,2], 2)]));
+        assert_eq!(PoeModule::claims_by_owner(2), claims);
+
+        // 批量销毁
+        assert_err!(
+            PoeModule::revoke_claims(Origin::signed(2), vec![vec![0,1], vec![9]]),
+            Error::<Test>::BatchItemFailed
+        );
+        assert!(Proofs::<Test>::contains_key(&vec![0,1]));
+        assert_ok!(PoeModule::revoke_claims(Origin::signed(2), claims.clone()));
+        assert_eq!(PoeModule::claims_by_owner(2), Vec::<Vec<u8>>::new());
+        assert_eq!(Balances::reserved_balance(2), 0);
+    })
+}
+
+#[test]
+fn genesis_config_should_seed_claims() {
+    new_test_ext_with_claims(vec![(vec![0,1], 1), (vec![0,2], 2)]).execute_with(|| {
+        // 创世存证照常质押押金，区块高度为0
+        assert_eq!(Proofs::<Test>::get(&vec![0,1]).unwrap(), ClaimInfo {
+            owner: 1,
+            block_number: 0,
+            moment: 0,
+            created_at: 0,
+            deposit: 12,
+            expiry: None,
+        });
+        assert_eq!(Proofs::<Test>::get(&vec![0,2]).unwrap().owner, 2);
+        assert_eq!(PoeModule::claims_by_owner(1), vec![vec![0,1]]);
+        assert_eq!(Balances::reserved_balance(1), 12);
+        assert_eq!(StorageVersion::<Test>::get(), Releases::V2_0_0);
+    })
+}
+
+#[test]
+#[should_panic(expected = "duplicate claim in genesis")]
+fn genesis_config_should_reject_duplicates() {
+    // 存证不能重复
+    new_test_ext_with_claims(vec![(vec![0,1], 1), (vec![0,1], 2)]);
+}
+
+#[test]
+#[should_panic(expected = "genesis claim is longer than StringLimit")]
+fn genesis_config_should_reject_long_proofs() {
+    // 存证长度不能超过 StringLimit
+    new_test_ext_with_claims(vec![(vec![0; StringLimit::get() as usize + 1], 1)]);
+}
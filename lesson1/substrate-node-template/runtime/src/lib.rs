@@ -100,10 +100,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("node-template"),
 	impl_name: create_runtime_str!("node-template"),
 	authoring_version: 1,
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 };
 
 
@@ -122,6 +122,11 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+// Balances are measured in units of 12 decimals.
+pub const UNITS: Balance = 1_000_000_000_000;
+pub const MILLIUNITS: Balance = UNITS / 1_000;
+pub const MICROUNITS: Balance = UNITS / 1_000_000;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -268,10 +273,77 @@ impl pallet_template::Config for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	// 0.01 UNITS per claim and 0.0001 UNITS per byte, far above the existential deposit.
+	pub const ClaimDepositBase: Balance = 10 * MILLIUNITS;
+	pub const ClaimDepositPerByte: Balance = 100 * MICROUNITS;
+	pub const MaxHistoryLength: u32 = 100;
+	pub const MaxClaimsPerAccount: u32 = 1_000;
+	pub const MaxClaimDuration: BlockNumber = 365 * DAYS;
+	pub const MaxExpiriesPerBlock: u32 = 50;
+	pub const MaxContentLength: u32 = 16 * 1024;
+	pub const MaxCoOwners: u32 = 10;
+	pub const MaxTitleLength: u32 = 128;
+	pub const MaxMimeTypeLength: u32 = 64;
+	pub const MaxUriLength: u32 = 256;
+	pub const MaxTags: u32 = 16;
+	pub const MaxTagLength: u32 = 32;
+	pub const ExpectedBlockTime: u64 = MILLISECS_PER_BLOCK;
+	pub const RevealWindow: BlockNumber = 7 * DAYS;
+	pub const MaxNotaries: u32 = 100;
+	pub const AttestationThreshold: u32 = 2;
+	// 0.1 UNITS, ten times the base deposit of a claim.
+	pub const ChallengeBond: Balance = 100 * MILLIUNITS;
+	pub const MaxBatchSize: u32 = 100;
+}
+
 /// Configure the template pallet in pallets/template.
 impl pallet_poe::Config for Runtime {
 	type Event = Event;
 	type StringLimit = StringLimit;
+	type Currency = Balances;
+	type ClaimDepositBase = ClaimDepositBase;
+	type ClaimDepositPerByte = ClaimDepositPerByte;
+	type MaxHistoryLength = MaxHistoryLength;
+	type MaxClaimsPerAccount = MaxClaimsPerAccount;
+	type MaxClaimDuration = MaxClaimDuration;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxContentLength = MaxContentLength;
+	type MaxCoOwners = MaxCoOwners;
+	type MaxTitleLength = MaxTitleLength;
+	type MaxMimeTypeLength = MaxMimeTypeLength;
+	type MaxUriLength = MaxUriLength;
+	type MaxTags = MaxTags;
+	type MaxTagLength = MaxTagLength;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type Signature = Signature;
+	type Signer = <Signature as Verify>::Signer;
+	type RevealWindow = RevealWindow;
+	type MaxNotaries = MaxNotaries;
+	type AttestationThreshold = AttestationThreshold;
+	type ChallengeBond = ChallengeBond;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxBatchSize = MaxBatchSize;
+	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkSigner = PoeBenchmarkSigner;
+}
+
+/// Signs the `create_claim_for` payloads of the poe benchmarks with a new sr25519 key.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PoeBenchmarkSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_poe::BenchmarkSigner<AccountId, Signature> for PoeBenchmarkSigner {
+	fn sign(payload: &[u8]) -> (AccountId, Signature) {
+		use sp_runtime::{app_crypto::sr25519, RuntimeAppPublic};
+
+		let public = sr25519::AppPublic::generate_pair(None);
+		let signature = public.sign(&payload).expect("the key pair was just generated; qed");
+		let signer: <Signature as Verify>::Signer = sp_core::sr25519::Public::from(public).into();
+		(signer.into_account(), sp_core::sr25519::Signature::from(signature).into())
+	}
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -291,7 +363,7 @@ construct_runtime!(
 		Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},
 		// Include the custom logic from the template pallet in the runtime.
 		TemplateModule: pallet_template::{Module, Call, Storage, Event<T>},
-		PoeModule: pallet_poe::{Module, Call, Storage, Event<T>},
+		PoeModule: pallet_poe::{Module, Call, Storage, Event<T>, Config<T>},
 	}
 );
 
@@ -444,6 +516,24 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_rpc_runtime_api::PoeApi<Block, AccountId, Hash> for Runtime {
+		fn claims_of(account: AccountId, page: u32) -> Vec<Vec<u8>> {
+			PoeModule::claims_of(&account, page)
+		}
+
+		fn verify_inclusion(root: Hash, leaf: Hash, proof: Vec<Hash>) -> bool {
+			PoeModule::verify_inclusion(root, leaf, &proof)
+		}
+
+		fn attestations_of(proof: Vec<u8>) -> Vec<AccountId> {
+			PoeModule::attestations(proof)
+		}
+
+		fn is_verified(proof: Vec<u8>) -> bool {
+			PoeModule::is_verified(&proof)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
 		for Runtime {
 		fn query_info(
@@ -494,6 +584,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, pallet_poe, PoeModule);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
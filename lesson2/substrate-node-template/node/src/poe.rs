@@ -0,0 +1,89 @@
+//! The `poe` subcommand, hashing a local file to check or build its proof-of-existence claim.
+
+use std::{path::PathBuf, sync::Arc};
+use codec::{Decode, Encode};
+use structopt::StructOpt;
+use sc_cli::{CliConfiguration, PruningParams, SharedParams};
+use sc_client_api::StorageProvider;
+use sp_blockchain::HeaderBackend;
+use sp_core::{hexdisplay::HexDisplay, storage::StorageKey};
+use sp_runtime::generic::BlockId;
+use node_template_runtime::{AccountId, Balance, BlockNumber, Call, Runtime};
+use pallet_poe::{ClaimInfo, HashAlgorithm, Proofs};
+use crate::service::FullClient;
+
+/// Hash a file, look its claim up in the local database and optionally build the call to claim it.
+#[derive(Debug, StructOpt)]
+pub struct PoeCmd {
+	/// The file to hash.
+	#[structopt(parse(from_os_str))]
+	pub file: PathBuf,
+
+	/// The algorithm used to hash the file: blake2-256, sha2-256 or keccak-256.
+	#[structopt(long, default_value = "blake2-256", parse(try_from_str = parse_algorithm))]
+	pub algorithm: HashAlgorithm,
+
+	/// Print the SCALE-encoded `create_hashed_claim` call claiming the digest with `--algorithm`,
+	/// ready for signing.
+	#[structopt(long)]
+	pub call: bool,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub pruning_params: PruningParams,
+}
+
+fn parse_algorithm(name: &str) -> Result<HashAlgorithm, String> {
+	match name {
+		"blake2-256" => Ok(HashAlgorithm::Blake2_256),
+		"sha2-256" => Ok(HashAlgorithm::Sha2_256),
+		"keccak-256" => Ok(HashAlgorithm::Keccak256),
+		_ => Err(format!("unknown hash algorithm `{}`", name)),
+	}
+}
+
+impl PoeCmd {
+	/// Run the command against the best block of `client`.
+	pub fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+		let content = std::fs::read(&self.file)?;
+		let digest = self.algorithm.hash(&content);
+		println!("digest: 0x{}", HexDisplay::from(&digest));
+		let proof = self.algorithm.tagged(&digest);
+
+		let best_hash = client.info().best_hash;
+		let key = StorageKey(Proofs::<Runtime>::hashed_key_for(&proof));
+		let claim = client.storage(&BlockId::Hash(best_hash), &key)
+			.map_err(|e| format!("failed to read the claim: {:?}", e))?;
+		match claim {
+			Some(data) => {
+				let claim = ClaimInfo::<AccountId, BlockNumber, Balance>::decode(&mut &data.0[..])
+					.map_err(|e| format!("failed to decode the claim: {:?}", e))?;
+				println!("owner: {}", claim.owner);
+				println!("block: {}", claim.block_number);
+				println!("created: {}", claim.created_at);
+			},
+			None => println!("not claimed"),
+		}
+
+		if self.call {
+			let call = Call::PoeModule(pallet_poe::Call::create_hashed_claim(self.algorithm, digest, None));
+			println!("call: 0x{}", HexDisplay::from(&call.encode()));
+		}
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for PoeCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+}
@@ -0,0 +1,17 @@
+//! Runtime API definition for the kitties pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait KittiesApi<KittyIndex> where
+		KittyIndex: Codec,
+	{
+		/// The kitties bred from `kitty_id`, oldest first.
+		fn children_of(kitty_id: KittyIndex) -> Vec<KittyIndex>;
+		/// The ancestors of `kitty_id` up to `depth` generations back, nearest generation first.
+		fn ancestors(kitty_id: KittyIndex, depth: u32) -> Vec<KittyIndex>;
+	}
+}
@@ -1,9 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*, traits::{Randomness, Currency, ReservableCurrency, ExistenceRequirement}, Parameter};
+use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*, traits::{Randomness, Currency, ReservableCurrency, ExistenceRequirement, BalanceStatus}, Parameter};
 use frame_system::pallet_prelude::*;
 use codec::{Encode, Decode};
-use sp_runtime::{traits::{AtLeast32BitUnsigned, Bounded, One, CheckedAdd}};
+use sp_runtime::{traits::{AtLeast32BitUnsigned, Bounded, One, CheckedAdd, Zero}};
 use sp_io::hashing::blake2_128;
+use sp_std::{vec, vec::Vec};
 
 pub use pallet::*;
 
@@ -20,6 +21,21 @@ pub mod pallet {
     #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
     pub struct Kitty(pub [u8; 16]);
 
+    /// The storage layouts of the pallet.
+    #[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+    pub enum Releases {
+        /// Kitties stored by owner only.
+        V1_0_0,
+        /// Kitties indexed by id and per owner, with their deposits.
+        V2_0_0,
+    }
+
+    impl Default for Releases {
+        fn default() -> Self {
+            Releases::V1_0_0
+        }
+    }
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config +  pallet_balances::Config{
@@ -28,6 +44,9 @@ pub mod pallet {
         type Randomness: Randomness<Self::Hash>;
         type KittyIndex: Parameter + AtLeast32BitUnsigned + Bounded + Default + Copy;
         type KittyReserve: Get<Self::Balance>;
+        /// The blocks a kitty of each generation has to wait between breedings, the last entry
+        /// applying to all later generations.
+        type BreedingCooldowns: Get<Vec<Self::BlockNumber>>;
     }
 
     // Pallets use events to inform users when important changes are made.
@@ -37,10 +56,11 @@ pub mod pallet {
     #[pallet::metadata(T::AccountId = "AccountId", T::KittyIndex = "KittyIndex")]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
-        KittyCreated(T::AccountId, T::KittyIndex, Kitty),
+        KittyCreated(T::AccountId, T::KittyIndex, Kitty, Option<(T::KittyIndex, T::KittyIndex)>),
         KittyTransferred(T::AccountId, T::AccountId, T::KittyIndex),
         KittyPriceUpdated(T::AccountId, T::KittyIndex, Option<T::Balance>),
         KittySold(T::AccountId, T::AccountId, T::KittyIndex, T::Balance),
+        KittyReleased(T::AccountId, T::KittyIndex),
     }
 
     #[pallet::error]
@@ -53,6 +73,9 @@ pub mod pallet {
         BuyFromSelf,
         NotForSale,
         MoneyNotEnough,
+        KittyOnCooldown,
+        InvalidPairing,
+        RecipientNotExist,
     }
 
     #[pallet::pallet]
@@ -77,8 +100,64 @@ pub mod pallet {
         T::Balance, OptionQuery
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn kitty_owner)]
+    pub type KittyOwner<T: Config> = StorageMap<_,
+        Blake2_128Concat, T::KittyIndex,
+        T::AccountId, OptionQuery
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn owned_kitties)]
+    pub type OwnedKitties<T: Config> = StorageMap<_,
+        Blake2_128Concat, T::AccountId,
+        Vec<T::KittyIndex>, ValueQuery
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn kitty_deposit)]
+    pub type KittyDeposits<T: Config> = StorageMap<_,
+        Blake2_128Concat, T::KittyIndex,
+        (T::AccountId, T::Balance), OptionQuery
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn parents_of)]
+    pub type KittyParents<T: Config> = StorageMap<_,
+        Blake2_128Concat, T::KittyIndex,
+        (T::KittyIndex, T::KittyIndex), OptionQuery
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn generation_of)]
+    pub type KittyGeneration<T: Config> = StorageMap<_,
+        Blake2_128Concat, T::KittyIndex,
+        u32, ValueQuery
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn children_of)]
+    pub type KittyChildren<T: Config> = StorageMap<_,
+        Blake2_128Concat, T::KittyIndex,
+        Vec<T::KittyIndex>, ValueQuery
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn ready_at)]
+    pub type BreedingReadyAt<T: Config> = StorageMap<_,
+        Blake2_128Concat, T::KittyIndex,
+        T::BlockNumber, ValueQuery
+    >;
+
+    #[pallet::storage]
+    pub type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            Self::migrate_to_v2()
+        }
+    }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
     // These functions materialize as "extrinsics", which are often compared to transactions.
@@ -89,18 +168,15 @@ pub mod pallet {
         pub fn create(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
 
-            <pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserve(&sender, T::KittyReserve::get())
-                .map_err(|_| Error::<T>::MoneyNotEnough )?;
-
-            let kitty_id = Self::get_and_add_kitty_id()?;
+            let kitty_id = Self::reserve_deposit(&sender)?;
 
             let dna = Self::random_value(&sender);
 
             let kitty = Kitty(dna);
 
-            Kitties::<T>::insert(&sender, kitty_id, &kitty);
+            Self::insert_kitty(&sender, kitty_id, &kitty);
 
-            Self::deposit_event(Event::KittyCreated(sender, kitty_id, kitty));
+            Self::deposit_event(Event::KittyCreated(sender, kitty_id, kitty, None));
             Ok(().into())
         }
 
@@ -108,19 +184,17 @@ pub mod pallet {
         pub fn transfer(origin: OriginFor<T>, to: T::AccountId, kitty_id: T::KittyIndex) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
 
-            Kitties::<T>::try_mutate_exists(sender.clone(), kitty_id, |kitty| -> DispatchResultWithPostInfo {
-                if sender == to {
-                    ensure!(kitty.is_some(), Error::<T>::InvalidKittyId);
-                    return Ok(().into());
-                }
-                let kitty = kitty.take().ok_or(Error::<T>::InvalidKittyId)?;
+            let kitty = Self::kitties(&sender, kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
 
-                Kitties::<T>::insert(&to, kitty_id, kitty);
+            if sender == to {
+                return Ok(().into());
+            }
 
-                Self::deposit_event(Event::KittyTransferred(sender, to, kitty_id));
+            Self::move_kitty(&sender, &to, kitty_id, kitty)?;
 
-                Ok(().into())
-            })
+            Self::deposit_event(Event::KittyTransferred(sender, to, kitty_id));
+
+            Ok(().into())
         }
 
         #[pallet::weight(1_000)]
@@ -132,7 +206,12 @@ pub mod pallet {
             let kitty_1 = Self::kitties(&sender, kitty_id_1).ok_or(Error::<T>::InvalidKittyId)?;
             let kitty_2 = Self::kitties(&sender, kitty_id_2).ok_or(Error::<T>::InvalidKittyId)?;
 
-            let kitty_id = Self::get_and_add_kitty_id()?;
+            ensure!(Self::can_pair(kitty_id_1, kitty_id_2), Error::<T>::InvalidPairing);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(Self::ready_at(kitty_id_1) <= now && Self::ready_at(kitty_id_2) <= now, Error::<T>::KittyOnCooldown);
+
+            let kitty_id = Self::reserve_deposit(&sender)?;
 
             let dna_1 = kitty_1.0;
             let dna_2 = kitty_2.0;
@@ -147,9 +226,12 @@ pub mod pallet {
 
             let kitty = Kitty(new_dna);
 
-            Kitties::<T>::insert(&sender, kitty_id, &kitty);
+            Self::insert_kitty(&sender, kitty_id, &kitty);
+            Self::record_lineage(kitty_id, kitty_id_1, kitty_id_2);
+            BreedingReadyAt::<T>::insert(kitty_id_1, now + Self::cooldown_of(kitty_id_1));
+            BreedingReadyAt::<T>::insert(kitty_id_2, now + Self::cooldown_of(kitty_id_2));
 
-            Self::deposit_event(Event::KittyCreated(sender, kitty_id, kitty));
+            Self::deposit_event(Event::KittyCreated(sender, kitty_id, kitty, Some((kitty_id_1, kitty_id_2))));
 
             Ok(().into())
         }
@@ -167,34 +249,130 @@ pub mod pallet {
         }
 
         #[pallet::weight(1_000)]
-        pub fn buy(origin: OriginFor<T>, owner: T::AccountId, kitty_id: T::KittyIndex, max_price: T::Balance) -> DispatchResultWithPostInfo {
+        pub fn buy(origin: OriginFor<T>, kitty_id: T::KittyIndex, max_price: T::Balance) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
 
+            let owner = Self::kitty_owner(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+
             ensure!(sender != owner, Error::<T>::BuyFromSelf);
 
-            Kitties::<T>::try_mutate_exists(owner.clone(), kitty_id, |kitty| -> DispatchResultWithPostInfo {
-                let kitty = kitty.take().ok_or(Error::<T>::InvalidKittyId)?;
-                KittiesPrice::<T>::try_mutate_exists(kitty_id, |price| -> DispatchResultWithPostInfo {
-                    let price = price.take().ok_or(Error::<T>::NotForSale)?;
+            let kitty = Self::kitties(&owner, kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+
+            KittiesPrice::<T>::try_mutate_exists(kitty_id, |price| -> DispatchResultWithPostInfo {
+                let price = price.take().ok_or(Error::<T>::NotForSale)?;
 
-                    ensure!(max_price >= price, Error::<T>::PriceTooLow);
+                ensure!(max_price >= price, Error::<T>::PriceTooLow);
 
-                    <pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(&sender, &owner, price, ExistenceRequirement::KeepAlive)?;
+                <pallet_balances::Pallet<T> as Currency<T::AccountId>>::transfer(&sender, &owner, price, ExistenceRequirement::KeepAlive)?;
 
-                    Kitties::<T>::insert(&sender, kitty_id, kitty);
+                Self::move_kitty(&owner, &sender, kitty_id, kitty)?;
 
-                    Self::deposit_event(Event::KittySold(sender, owner, kitty_id, price));
+                Self::deposit_event(Event::KittySold(sender, owner, kitty_id, price));
 
-                    Ok(().into())
-                })
+                Ok(().into())
             })
         }
+
+        #[pallet::weight(1_000)]
+        pub fn release(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Kitties::<T>::contains_key(&sender, kitty_id), Error::<T>::NotOwner);
+
+            Kitties::<T>::remove(&sender, kitty_id);
+            KittyOwner::<T>::remove(kitty_id);
+            OwnedKitties::<T>::mutate(&sender, |owned| owned.retain(|id| *id != kitty_id));
+            KittiesPrice::<T>::remove(kitty_id);
+
+            if let Some((holder, amount)) = KittyDeposits::<T>::take(kitty_id) {
+                <pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::unreserve(&holder, amount);
+            }
+
+            Self::deposit_event(Event::KittyReleased(sender, kitty_id));
+
+            Ok(().into())
+        }
     }
 }
 
 
 
 impl<T: Config> Pallet<T> {
+    /// The ancestors of `kitty_id` up to `depth` generations back, nearest generation first.
+    pub fn ancestors(kitty_id: T::KittyIndex, depth: u32) -> Vec<T::KittyIndex> {
+        let mut ancestors = Vec::new();
+        let mut generation = vec![kitty_id];
+
+        for _ in 0..depth {
+            let mut parents = Vec::new();
+            for id in generation.iter() {
+                if let Some((parent_1, parent_2)) = Self::parents_of(id) {
+                    for parent in [parent_1, parent_2].iter() {
+                        if !ancestors.contains(parent) {
+                            ancestors.push(*parent);
+                            parents.push(*parent);
+                        }
+                    }
+                }
+            }
+            if parents.is_empty() {
+                break;
+            }
+            generation = parents;
+        }
+
+        ancestors
+    }
+
+    /// Index the kitties stored before `V2_0_0` by id and under their owner, who is recorded as
+    /// the holder of a `KittyReserve` deposit.
+    fn migrate_to_v2() -> Weight {
+        if StorageVersion::<T>::get() != Releases::V1_0_0 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut kitties: Weight = 0;
+        let mut migrated: Weight = 0;
+        for (owner, kitty_id, _) in Kitties::<T>::iter() {
+            kitties += 1;
+            // Kitties created since the upgrade are indexed already.
+            if KittyOwner::<T>::contains_key(kitty_id) {
+                continue;
+            }
+            KittyOwner::<T>::insert(kitty_id, &owner);
+            OwnedKitties::<T>::append(&owner, kitty_id);
+            KittyDeposits::<T>::insert(kitty_id, (&owner, T::KittyReserve::get()));
+            migrated += 1;
+        }
+
+        StorageVersion::<T>::put(Releases::V2_0_0);
+        T::DbWeight::get().reads_writes(1 + kitties * 2, 1 + migrated * 3)
+    }
+
+    fn can_pair(kitty_id_1: T::KittyIndex, kitty_id_2: T::KittyIndex) -> bool {
+        match (Self::parents_of(kitty_id_1), Self::parents_of(kitty_id_2)) {
+            (Some((a, b)), _) if a == kitty_id_2 || b == kitty_id_2 => false,
+            (_, Some((a, b))) if a == kitty_id_1 || b == kitty_id_1 => false,
+            (Some((a_1, b_1)), Some((a_2, b_2))) => a_1 != a_2 && a_1 != b_2 && b_1 != a_2 && b_1 != b_2,
+            _ => true,
+        }
+    }
+
+    fn cooldown_of(kitty_id: T::KittyIndex) -> T::BlockNumber {
+        let schedule = T::BreedingCooldowns::get();
+        let generation = Self::generation_of(kitty_id) as usize;
+        schedule.get(generation).or_else(|| schedule.last()).cloned().unwrap_or_else(Zero::zero)
+    }
+
+    fn record_lineage(kitty_id: T::KittyIndex, parent_1: T::KittyIndex, parent_2: T::KittyIndex) {
+        let generation = Self::generation_of(parent_1).max(Self::generation_of(parent_2)).saturating_add(1);
+
+        KittyParents::<T>::insert(kitty_id, (parent_1, parent_2));
+        KittyGeneration::<T>::insert(kitty_id, generation);
+        KittyChildren::<T>::append(parent_1, kitty_id);
+        KittyChildren::<T>::append(parent_2, kitty_id);
+    }
+
     fn random_value(sender: &T::AccountId) -> [u8; 16] {
         let payload = (
             T::Randomness::random_seed(),
@@ -208,12 +386,41 @@ impl<T: Config> Pallet<T> {
         (!selector & dna1) | (selector & dna2)
     }
 
-    fn get_and_add_kitty_id() -> Result<T::KittyIndex, DispatchError> {
-        KittyId::<T>::try_mutate(|next_id| -> Result<T::KittyIndex, DispatchError> {
-            let current_id = *next_id;
-            *next_id = next_id.checked_add(&One::one()).ok_or(Error::<T>::KittiesCountOverFlow)?;
-            Ok(current_id)
-        })
+    fn insert_kitty(owner: &T::AccountId, kitty_id: T::KittyIndex, kitty: &Kitty) {
+        Kitties::<T>::insert(owner, kitty_id, kitty);
+        KittyOwner::<T>::insert(kitty_id, owner);
+        OwnedKitties::<T>::append(owner, kitty_id);
+    }
+
+    fn move_kitty(from: &T::AccountId, to: &T::AccountId, kitty_id: T::KittyIndex, kitty: Kitty) -> DispatchResult {
+        // The deposit can only be repatriated to an existing account.
+        ensure!(<frame_system::Pallet<T>>::account_exists(to), Error::<T>::RecipientNotExist);
+
+        if let Some((holder, amount)) = Self::kitty_deposit(kitty_id) {
+            let remaining = <pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::repatriate_reserved(&holder, to, amount, BalanceStatus::Reserved)?;
+            KittyDeposits::<T>::insert(kitty_id, (to, amount - remaining));
+        }
+
+        Kitties::<T>::remove(from, kitty_id);
+        OwnedKitties::<T>::mutate(from, |owned| owned.retain(|id| *id != kitty_id));
+        // A price set by the previous owner does not carry over.
+        KittiesPrice::<T>::remove(kitty_id);
+        Self::insert_kitty(to, kitty_id, &kitty);
+        Ok(())
+    }
+
+    fn reserve_deposit(who: &T::AccountId) -> Result<T::KittyIndex, DispatchError> {
+        // Allocate the id first so that an overflow leaves nothing reserved.
+        let kitty_id = Self::next_kitty_id();
+        let next_id = kitty_id.checked_add(&One::one()).ok_or(Error::<T>::KittiesCountOverFlow)?;
+
+        let amount = T::KittyReserve::get();
+        <pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserve(who, amount)
+            .map_err(|_| Error::<T>::MoneyNotEnough )?;
+
+        KittyId::<T>::put(next_id);
+        KittyDeposits::<T>::insert(kitty_id, (who, amount));
+        Ok(kitty_id)
     }
 }
 
@@ -76,6 +76,7 @@ impl Randomness<H256> for MockRandom {
 
 parameter_types! {
     pub const KittyReserve: u64 = 100;
+    pub BreedingCooldowns: Vec<u64> = vec![5, 10];
 }
 
 impl kitties::Config for Test {
@@ -83,6 +84,7 @@ impl kitties::Config for Test {
     type Randomness = MockRandom;
     type KittyIndex = u32;
     type KittyReserve = KittyReserve;
+    type BreedingCooldowns = BreedingCooldowns;
 }
 
 // BUild genesis storage according to the mock runtime.
@@ -12,9 +12,12 @@ fn create_should_work() {
         assert_eq!(Balances::free_balance(1), 400);
         assert_eq!(Balances::reserved_balance(1), 100);
         assert_eq!(KittiesModule::next_kitty_id(), 1);
+        assert_eq!(KittiesModule::kitty_owner(0), Some(1));
+        assert_eq!(KittiesModule::owned_kitties(1), vec![0]);
+        assert_eq!(KittiesModule::kitty_deposit(0), Some((1, 100)));
         assert_eq!(
             System::events()[1].event,
-            mock::Event::kitties(crate::Event::<Test>::KittyCreated(1, 0, kitty))
+            mock::Event::kitties(crate::Event::<Test>::KittyCreated(1, 0, kitty, None))
         );
     });
 }
@@ -29,6 +32,18 @@ fn create_should_failed_when_money_not_enough() {
     });
 }
 
+#[test]
+fn create_should_failed_when_kitties_count_overflow() {
+    new_test_ext().execute_with(|| {
+        KittyId::<Test>::put(u32::max_value());
+        assert_noop!(
+            KittiesModule::create(Origin::signed(1)),
+            Error::<Test>::KittiesCountOverFlow
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
 #[test]
 fn transfer_should_work() {
     new_test_ext().execute_with(|| {
@@ -37,13 +52,52 @@ fn transfer_should_work() {
         assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 0));
         assert_eq!(Kitties::<Test>::contains_key(1, 0), false);
         assert_eq!(Kitties::<Test>::contains_key(2, 0), true);
-        // event[reserve, create, transfer]
+        assert_eq!(KittiesModule::kitty_owner(0), Some(2));
+        assert_eq!(KittiesModule::owned_kitties(1), Vec::<u32>::new());
+        assert_eq!(KittiesModule::owned_kitties(2), vec![0]);
+        // the deposit follows the kitty
+        assert_eq!(KittiesModule::kitty_deposit(0), Some((2, 100)));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 400);
+        assert_eq!(Balances::reserved_balance(2), 100);
+        assert_eq!(Balances::free_balance(2), 500);
+        // event[reserve, create, repatriate, transfer]
         assert_eq!(
-            System::events()[2].event,
+            System::events()[3].event,
             mock::Event::kitties(crate::Event::<Test>::KittyTransferred(1, 2, 0))
         );
         assert_ok!(KittiesModule::transfer(Origin::signed(2), 2, 0));
         assert_eq!(Kitties::<Test>::contains_key(2, 0), true);
+        assert_eq!(KittiesModule::owned_kitties(2), vec![0]);
+    });
+}
+
+#[test]
+fn transfer_should_clear_the_price() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(200)));
+        assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 0));
+        assert_eq!(KittiesPrice::<Test>::contains_key(0), false);
+        // the new owner did not put the kitty on sale
+        assert_noop!(
+            KittiesModule::buy(Origin::signed(3), 0, 200),
+            Error::<Test>::NotForSale
+        );
+    });
+}
+
+#[test]
+fn transfer_should_failed_when_recipient_not_exist() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        // account 4 has no balance to hold the deposit
+        assert_noop!(
+            KittiesModule::transfer(Origin::signed(1), 4, 0),
+            Error::<Test>::RecipientNotExist
+        );
+        assert_eq!(KittiesModule::kitty_owner(0), Some(1));
+        assert_eq!(Balances::reserved_balance(1), 100);
     });
 }
 
@@ -62,10 +116,102 @@ fn breed_should_work() {
         assert_ok!(KittiesModule::create(Origin::signed(1)));
         assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1));
         assert_eq!(Kitties::<Test>::contains_key(1, 2), true);
+        assert_eq!(KittiesModule::kitty_owner(2), Some(1));
+        assert_eq!(KittiesModule::owned_kitties(1), vec![0, 1, 2]);
+        // breeding reserves a deposit too
+        assert_eq!(KittiesModule::kitty_deposit(2), Some((1, 100)));
+        assert_eq!(Balances::reserved_balance(1), 300);
         let kitty = Kitty([39, 140, 77, 194, 163, 1, 154, 220, 108, 18, 30, 32, 100, 223, 46, 1]);
         assert_eq!(
-            System::events()[4].event,
-            mock::Event::kitties(crate::Event::<Test>::KittyCreated(1, 2, kitty))
+            System::events()[5].event,
+            mock::Event::kitties(crate::Event::<Test>::KittyCreated(1, 2, kitty, Some((0, 1))))
+        );
+    });
+}
+
+#[test]
+fn breed_should_record_lineage() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1)); // 3
+        assert_ok!(KittiesModule::breed(Origin::signed(1), 3, 2)); // 4
+        assert_eq!(KittiesModule::parents_of(0), None);
+        assert_eq!(KittiesModule::parents_of(4), Some((3, 2)));
+        assert_eq!(KittiesModule::generation_of(0), 0);
+        assert_eq!(KittiesModule::generation_of(3), 1);
+        assert_eq!(KittiesModule::generation_of(4), 2);
+        assert_eq!(KittiesModule::children_of(0), vec![3]);
+        assert_eq!(KittiesModule::children_of(3), vec![4]);
+        assert_eq!(KittiesModule::ancestors(4, 1), vec![3, 2]);
+        assert_eq!(KittiesModule::ancestors(4, 5), vec![3, 2, 0, 1]);
+        assert_eq!(KittiesModule::ancestors(0, 5), Vec::<u32>::new());
+
+        // lineage outlives released kitties
+        assert_ok!(KittiesModule::release(Origin::signed(1), 3));
+        assert_eq!(KittiesModule::parents_of(3), Some((0, 1)));
+        assert_eq!(KittiesModule::ancestors(4, 2), vec![3, 2, 0, 1]);
+    });
+}
+
+#[test]
+fn breed_should_failed_when_on_cooldown() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1)); // 3
+        // generation 0 kitties wait 5 blocks
+        assert_eq!(KittiesModule::ready_at(0), 6);
+        assert_noop!(
+            KittiesModule::breed(Origin::signed(1), 0, 2),
+            Error::<Test>::KittyOnCooldown
+        );
+        System::set_block_number(6);
+        assert_ok!(KittiesModule::breed(Origin::signed(1), 3, 2)); // 4
+        // later generations wait longer
+        assert_eq!(KittiesModule::ready_at(3), 16);
+        assert_eq!(KittiesModule::ready_at(2), 11);
+    });
+}
+
+#[test]
+fn breed_should_failed_when_pairing_parent_or_sibling() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1)); // 3
+        System::set_block_number(6);
+        assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 2)); // 4
+        // parent and child
+        assert_noop!(
+            KittiesModule::breed(Origin::signed(1), 3, 0),
+            Error::<Test>::InvalidPairing
+        );
+        assert_noop!(
+            KittiesModule::breed(Origin::signed(1), 2, 4),
+            Error::<Test>::InvalidPairing
+        );
+        // half siblings
+        assert_noop!(
+            KittiesModule::breed(Origin::signed(1), 3, 4),
+            Error::<Test>::InvalidPairing
+        );
+    });
+}
+
+#[test]
+fn breed_should_failed_when_money_not_enough() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::transfer(Origin::signed(1), 3, 0));
+        assert_ok!(KittiesModule::transfer(Origin::signed(1), 3, 1));
+        assert_noop!(
+            KittiesModule::breed(Origin::signed(3), 0, 1),
+            Error::<Test>::MoneyNotEnough
         );
     });
 }
@@ -111,26 +257,48 @@ fn buy_should_work() {
     new_test_ext().execute_with(|| {
         assert_ok!(KittiesModule::create(Origin::signed(1))); //2
         assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(200))); // 1
-        assert_ok!(KittiesModule::buy(Origin::signed(2), 1, 0, 200)); // 2
+        assert_ok!(KittiesModule::buy(Origin::signed(2), 0, 200)); // 2
         assert_eq!(Balances::free_balance(1), 600);
         assert_eq!(Balances::free_balance(2), 300);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 100);
+        assert_eq!(KittiesModule::kitty_deposit(0), Some((2, 100)));
         assert_eq!(
-            System::events()[4].event,
+            System::events()[5].event,
             mock::Event::kitties(crate::Event::<Test>::KittySold(2, 1, 0, 200))
         );
         assert_eq!(KittiesPrice::<Test>::contains_key(0), false);
         assert_eq!(Kitties::<Test>::contains_key(1, 0), false);
+        assert_eq!(Kitties::<Test>::contains_key(2, 0), true);
+        assert_eq!(KittiesModule::kitty_owner(0), Some(2));
+        assert_eq!(KittiesModule::owned_kitties(1), Vec::<u32>::new());
+        assert_eq!(KittiesModule::owned_kitties(2), vec![0]);
+    });
+}
+
+#[test]
+fn owned_kitties_should_keep_acquisition_order() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(2)));
+        assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 0));
+        assert_eq!(KittiesModule::owned_kitties(1), vec![1]);
+        assert_eq!(KittiesModule::owned_kitties(2), vec![2, 0]);
+        assert_ok!(KittiesModule::transfer(Origin::signed(2), 1, 2));
+        assert_eq!(KittiesModule::owned_kitties(1), vec![1, 2]);
+        assert_eq!(KittiesModule::owned_kitties(2), vec![0]);
     });
 }
 
 
 #[test]
-fn buy_should_failed_when_invalid_owner() {
+fn buy_should_failed_when_invalid_kitty_id() {
     new_test_ext().execute_with(|| {
         assert_ok!(KittiesModule::create(Origin::signed(1)));
         assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(100)));
         assert_noop!(
-            KittiesModule::buy(Origin::signed(200), 2, 0, 100),
+            KittiesModule::buy(Origin::signed(200), 1, 100),
             Error::<Test>::InvalidKittyId
         );
     });
@@ -141,7 +309,7 @@ fn buy_should_failed_when_not_for_sale() {
     new_test_ext().execute_with(|| {
         assert_ok!(KittiesModule::create(Origin::signed(1)));
         assert_noop!(
-            KittiesModule::buy(Origin::signed(200), 1, 0, 100),
+            KittiesModule::buy(Origin::signed(200), 0, 100),
             Error::<Test>::NotForSale
         );
     });
@@ -153,7 +321,7 @@ fn buy_should_failed_when_max_price_too_low() {
         assert_ok!(KittiesModule::create(Origin::signed(1)));
         assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(100)));
         assert_noop!(
-            KittiesModule::buy(Origin::signed(200), 1, 0, 10),
+            KittiesModule::buy(Origin::signed(200), 0, 10),
             Error::<Test>::PriceTooLow
         );
     });
@@ -165,8 +333,98 @@ fn buy_should_failed_when_buy_from_self() {
         assert_ok!(KittiesModule::create(Origin::signed(1)));
         assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(100)));
         assert_noop!(
-            KittiesModule::buy(Origin::signed(1), 1, 0, 100),
+            KittiesModule::buy(Origin::signed(1), 0, 100),
             Error::<Test>::BuyFromSelf
         );
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn release_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::set_price(Origin::signed(1), 0, Some(100)));
+        assert_ok!(KittiesModule::release(Origin::signed(1), 0));
+        assert_eq!(Kitties::<Test>::contains_key(1, 0), false);
+        assert_eq!(KittiesModule::kitty_owner(0), None);
+        assert_eq!(KittiesModule::owned_kitties(1), vec![1]);
+        assert_eq!(KittiesPrice::<Test>::contains_key(0), false);
+        assert_eq!(KittiesModule::kitty_deposit(0), None);
+        assert_eq!(Balances::reserved_balance(1), 100);
+        assert_eq!(Balances::free_balance(1), 400);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::kitties(crate::Event::<Test>::KittyReleased(1, 0))
+        );
+        // a released kitty can no longer be bought
+        assert_noop!(
+            KittiesModule::buy(Origin::signed(2), 0, 100),
+            Error::<Test>::InvalidKittyId
+        );
+    });
+}
+
+#[test]
+fn release_should_unreserve_the_deposit_holder() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 0));
+        assert_ok!(KittiesModule::release(Origin::signed(2), 0));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 600);
+    });
+}
+
+#[test]
+fn release_should_failed_when_not_owner() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+        assert_noop!(
+            KittiesModule::release(Origin::signed(2), 0),
+            Error::<Test>::NotOwner
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v2_should_index_existing_kitties() {
+    new_test_ext().execute_with(|| {
+        // kitties stored without indexes or deposits
+        let kitty = Kitty([0; 16]);
+        Kitties::<Test>::insert(1, 0, kitty.clone());
+        Kitties::<Test>::insert(2, 1, kitty.clone());
+        Kitties::<Test>::insert(2, 2, kitty);
+        KittyId::<Test>::put(3);
+        // a kitty created by the new code before the upgrade runs
+        assert_ok!(KittiesModule::create(Origin::signed(1)));
+
+        KittiesModule::migrate_to_v2();
+        assert_eq!(StorageVersion::<Test>::get(), Releases::V2_0_0);
+        assert_eq!(KittiesModule::kitty_owner(0), Some(1));
+        assert_eq!(KittiesModule::kitty_owner(1), Some(2));
+        assert_eq!(KittiesModule::kitty_owner(2), Some(2));
+        let mut owned = KittiesModule::owned_kitties(2);
+        owned.sort();
+        assert_eq!(owned, vec![1, 2]);
+        assert_eq!(KittiesModule::kitty_deposit(0), Some((1, 100)));
+        assert_eq!(KittiesModule::kitty_deposit(2), Some((2, 100)));
+
+        // the kitty indexed already is left alone
+        let mut owned = KittiesModule::owned_kitties(1);
+        owned.sort();
+        assert_eq!(owned, vec![0, 3]);
+        assert_eq!(KittiesModule::kitty_deposit(3), Some((1, 100)));
+
+        // the migrated kitties can be sold like any other
+        assert_ok!(KittiesModule::set_price(Origin::signed(2), 1, Some(10)));
+        assert_ok!(KittiesModule::buy(Origin::signed(1), 1, 10));
+        assert_eq!(KittiesModule::kitty_owner(1), Some(1));
+        assert_eq!(KittiesModule::owned_kitties(2), vec![2]);
+
+        // a second run changes nothing
+        KittiesModule::migrate_to_v2();
+        assert_eq!(KittiesModule::owned_kitties(2), vec![2]);
+    });
+}
@@ -0,0 +1,153 @@
+//! RPC interface for the poe pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_poe_rpc_runtime_api::PoeApi as PoeRuntimeApi;
+pub use self::gen_client::Client as PoeClient;
+
+/// Poe RPC methods.
+#[rpc]
+pub trait PoeApi<BlockHash, AccountId, Hash> {
+	/// The proofs owned by `account`, one page at a time starting from page 0.
+	#[rpc(name = "poe_claimsOf")]
+	fn claims_of(&self, account: AccountId, page: u32, at: Option<BlockHash>) -> Result<Vec<Bytes>>;
+
+	/// Whether `leaf` is part of the anchored batch with the given `root`.
+	#[rpc(name = "poe_verifyInclusion")]
+	fn verify_inclusion(
+		&self,
+		root: Hash,
+		leaf: Hash,
+		proof: Vec<Hash>,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+
+	/// The notaries which attested `proof`.
+	#[rpc(name = "poe_attestationsOf")]
+	fn attestations_of(&self, proof: Bytes, at: Option<BlockHash>) -> Result<Vec<AccountId>>;
+
+	/// Whether `proof` is attested by enough registered notaries.
+	#[rpc(name = "poe_isVerified")]
+	fn is_verified(&self, proof: Bytes, at: Option<BlockHash>) -> Result<bool>;
+}
+
+/// A struct that implements the [`PoeApi`].
+pub struct Poe<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Poe<C, B> {
+	/// Create new `Poe` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, Hash> PoeApi<<Block as BlockT>::Hash, AccountId, Hash> for Poe<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: PoeRuntimeApi<Block, AccountId, Hash>,
+	AccountId: Codec,
+	Hash: Codec,
+{
+	fn claims_of(
+		&self,
+		account: AccountId,
+		page: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<Bytes>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.claims_of(&at, account, page)
+			.map(|claims| claims.into_iter().map(Into::into).collect())
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query claims.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn verify_inclusion(
+		&self,
+		root: Hash,
+		leaf: Hash,
+		proof: Vec<Hash>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.verify_inclusion(&at, root, leaf, proof).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to verify inclusion proof.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn attestations_of(
+		&self,
+		proof: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.attestations_of(&at, proof.to_vec()).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query attestations.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn is_verified(
+		&self,
+		proof: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.is_verified(&at, proof.to_vec()).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query verification.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
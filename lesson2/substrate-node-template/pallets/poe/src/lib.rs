@@ -9,11 +9,201 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+    use frame_support::{
+        dispatch::{DispatchResult, DispatchResultWithPostInfo}, pallet_prelude::*,
+        storage::{with_transaction, TransactionOutcome},
+        traits::{BalanceStatus, Currency, ReservableCurrency, UnixTime},
+    };
     use frame_system::pallet_prelude::*;
-    use sp_std::vec::Vec; // Step 3.1 will include this in `Cargo.toml`
+    use sp_io::hashing::{blake2_256, keccak_256, sha2_256};
+    use sp_runtime::traits::{Hash as HashT, IdentifyAccount, One, Saturating, Verify, Zero};
+    use sp_std::{vec, vec::Vec}; // Step 3.1 will include this in `Cargo.toml`
+
+    use crate::weights::WeightInfo;
+
+    /// The number of proofs returned per page by [`Pallet::claims_of`].
+    pub const CLAIMS_PAGE_SIZE: u32 = 20;
+
+    /// The depth of a Merkle tree holding the maximum of `u32::max_value()` leaves.
+    pub const MAX_MERKLE_DEPTH: u32 = 32;
+
+    /// The byte prepended to a document hash before it is hashed into a Merkle leaf.
+    pub const MERKLE_LEAF_PREFIX: u8 = 0;
+
+    /// The byte prepended to a pair of Merkle nodes before they are hashed into their parent.
+    pub const MERKLE_NODE_PREFIX: u8 = 1;
+
+    /// The length of the key of a hashed claim: a one-byte algorithm tag and a 32-byte digest.
+    pub const HASHED_PROOF_LENGTH: u32 = 33;
+
+    /// The tag of the payload signed for `create_claim_for`, so that it is not valid for other calls.
+    pub const DELEGATED_CLAIM_CONTEXT: &[u8] = b"pallet-poe:create_claim_for";
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Information stored on-chain for every claimed proof.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct ClaimInfo<AccountId, BlockNumber, Balance> {
+        /// The account owning the claim.
+        pub owner: AccountId,
+        /// The block at which the claim was created or last transferred.
+        pub block_number: BlockNumber,
+        /// The Unix time in milliseconds at `block_number`.
+        pub moment: u64,
+        /// The Unix time in milliseconds at which the claim was first made, kept across transfers.
+        pub created_at: u64,
+        /// The amount reserved from the owner for storing the claim and its metadata.
+        pub deposit: Balance,
+        /// The block at which the claim expires, if any.
+        pub expiry: Option<BlockNumber>,
+    }
+
+    /// Descriptive metadata attached to a claim, bounded by the `Max*` constants of the pallet.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct ClaimMetadata {
+        /// A human readable title of the document.
+        pub title: Vec<u8>,
+        /// The MIME type of the document, e.g. `application/pdf`.
+        pub mime_type: Vec<u8>,
+        /// Where the document can be retrieved from, e.g. an IPFS CID.
+        pub uri: Vec<u8>,
+        /// Free-form tags.
+        pub tags: Vec<Vec<u8>>,
+    }
+
+    /// The algorithm used to compute the digest of a hashed claim.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum HashAlgorithm {
+        /// BLAKE2b with a 256-bit output.
+        Blake2_256,
+        /// SHA-256.
+        Sha2_256,
+        /// Keccak-256, as used by Ethereum.
+        Keccak256,
+    }
+
+    impl HashAlgorithm {
+        /// The 32-byte digest of `data`.
+        pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+            match self {
+                HashAlgorithm::Blake2_256 => blake2_256(data),
+                HashAlgorithm::Sha2_256 => sha2_256(data),
+                HashAlgorithm::Keccak256 => keccak_256(data),
+            }
+        }
+
+        /// The key of the claim of `digest` computed with this algorithm.
+        ///
+        /// It is the encoded algorithm followed by the digest, so that equal digests of different
+        /// algorithms are claimed independently.
+        pub fn tagged(&self, digest: &[u8; 32]) -> Vec<u8> {
+            (self, digest).encode()
+        }
+    }
+
+    /// The approval state of a co-signed claim.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum ClaimStatus {
+        /// Fewer than `threshold` co-owners have approved the claim.
+        Pending,
+        /// Enough co-owners have approved the claim.
+        Finalized,
+    }
+
+    /// The co-owners of a co-signed claim and their approvals.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub struct CoSignInfo<AccountId> {
+        /// The accounts which co-own the claim, including its creator.
+        pub co_owners: Vec<AccountId>,
+        /// The number of co-owners needed to finalize, revoke or transfer the claim.
+        pub threshold: u32,
+        /// The co-owners which have approved the claim so far.
+        pub approvals: Vec<AccountId>,
+        /// Whether the claim has been finalized.
+        pub status: ClaimStatus,
+    }
+
+    /// An action on a co-signed claim which needs the approval of `threshold` co-owners.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+    pub enum ClaimAction<AccountId, BlockNumber> {
+        /// Revoke the claim.
+        Revoke,
+        /// Transfer the claim to the given account.
+        Transfer(AccountId),
+        /// Change the expiry of the claim.
+        Renew(Option<BlockNumber>),
+    }
+
+    /// Information stored on-chain for every anchored batch of documents.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct BatchInfo<AccountId, BlockNumber, Balance> {
+        /// The account which anchored the batch.
+        pub owner: AccountId,
+        /// The block at which the batch was anchored.
+        pub block_number: BlockNumber,
+        /// The Unix time in milliseconds at which the batch was anchored.
+        pub moment: u64,
+        /// The number of documents in the batch.
+        pub leaf_count: u32,
+        /// The amount reserved from the owner for storing the batch.
+        pub deposit: Balance,
+    }
+
+    /// An open challenge against the owner of a claim.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct DisputeInfo<AccountId, Balance, Hash> {
+        /// The account which challenged the claim.
+        pub challenger: AccountId,
+        /// The hash of the evidence brought forward by the challenger.
+        pub evidence: Hash,
+        /// The amount reserved from the challenger until the dispute is resolved.
+        pub bond: Balance,
+    }
+
+    /// A salted commitment to a proof that has not been revealed yet.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+    pub struct CommitmentInfo<AccountId, BlockNumber, Balance> {
+        /// The account which committed to the proof.
+        pub owner: AccountId,
+        /// The block of the commitment, kept as the priority date of the revealed claim.
+        pub block_number: BlockNumber,
+        /// The Unix time in milliseconds of the commitment.
+        pub moment: u64,
+        /// The amount reserved from the owner until the commitment is revealed or reaped.
+        pub deposit: Balance,
+    }
+
+    /// The storage layout of the pallet, used to decide which migrations to run.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum Releases {
+        /// `Proofs` holds `(owner, block number)` tuples.
+        V1_0_0,
+        /// `Proofs` holds a `ClaimInfo` for every claimed proof.
+        V2_0_0,
+    }
+
+    impl Default for Releases {
+        fn default() -> Self {
+            Releases::V1_0_0
+        }
+    }
+
+    /// Produces the owner signatures of `create_claim_for` in benchmarks.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkSigner<AccountId, Signature> {
+        /// A new account and its signature of `payload`.
+        fn sign(payload: &[u8]) -> (AccountId, Signature);
+    }
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
@@ -22,19 +212,130 @@ pub mod pallet {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         /// The maximum length of proof stored on-chain.
         type StringLimit: Get<u32>;
+        /// The currency in which claim deposits are reserved.
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// The base amount reserved for every claim.
+        type ClaimDepositBase: Get<BalanceOf<Self>>;
+        /// The additional amount reserved for every byte of the proof and of its encoded metadata.
+        type ClaimDepositPerByte: Get<BalanceOf<Self>>;
+        /// The maximum number of owners recorded in the history of a claim. The creator is always
+        /// kept, the oldest later owners are dropped first. At least 2.
+        type MaxHistoryLength: Get<u32>;
+        /// The maximum number of claims a single account may own.
+        type MaxClaimsPerAccount: Get<u32>;
+        /// The maximum number of blocks a claim may stay valid for before it has to be renewed.
+        type MaxClaimDuration: Get<Self::BlockNumber>;
+        /// The maximum number of claims expiring at a single block, all pruned in that block.
+        type MaxExpiriesPerBlock: Get<u32>;
+        /// The maximum length of content hashed on-chain by `hash_and_claim`.
+        type MaxContentLength: Get<u32>;
+        /// The maximum number of co-owners of a co-signed claim.
+        type MaxCoOwners: Get<u32>;
+        /// The maximum length of the title of a claim.
+        type MaxTitleLength: Get<u32>;
+        /// The maximum length of the MIME type of a claim.
+        type MaxMimeTypeLength: Get<u32>;
+        /// The maximum length of the URI of a claim.
+        type MaxUriLength: Get<u32>;
+        /// The maximum number of tags of a claim.
+        type MaxTags: Get<u32>;
+        /// The maximum length of a single tag.
+        type MaxTagLength: Get<u32>;
+        /// The source of the Unix time recorded with every claim.
+        type UnixTime: UnixTime;
+        /// The expected time between blocks in milliseconds, used to estimate the moment of
+        /// claims created before moments were recorded.
+        type ExpectedBlockTime: Get<u64>;
+        /// The signature owners use to authorize `create_claim_for`.
+        type Signature: Verify<Signer = Self::Signer> + Parameter;
+        /// The public key behind a `Signature`, identifying the owner's account.
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
+        /// The number of blocks after a commitment during which it can be revealed.
+        type RevealWindow: Get<Self::BlockNumber>;
+        /// The maximum number of registered notaries.
+        type MaxNotaries: Get<u32>;
+        /// The number of registered notaries which have to attest a claim before it is verified.
+        type AttestationThreshold: Get<u32>;
+        /// The bond reserved from an account challenging a claim.
+        type ChallengeBond: Get<BalanceOf<Self>>;
+        /// The origin which may force, freeze and thaw claims.
+        type ForceOrigin: EnsureOrigin<Self::Origin>;
+        /// The maximum number of claims handled by a single batch call.
+        type MaxBatchSize: Get<u32>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+        /// Produces the owner signatures of `create_claim_for` in benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkSigner: BenchmarkSigner<Self::AccountId, Self::Signature>;
     }
 
     // Pallets use events to inform users when important changes are made.
     // Event documentation should end with an array that provides descriptive names for parameters.
     // https://substrate.dev/docs/en/knowledgebase/runtime/events
     #[pallet::event]
-    #[pallet::metadata(T::AccountId = "AccountId")]
+    #[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber", T::Hash = "Hash")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Event emitted when a proof has been claimed. [who, claim]
-        ClaimCreated(T::AccountId, Vec<u8>),
+        /// Event emitted when a proof has been claimed. [who, claim, moment]
+        ClaimCreated(T::AccountId, Vec<u8>, u64),
         /// Event emitted when a claim is revoked by the owner. [who, claim]
         ClaimRevoked(T::AccountId, Vec<u8>),
+        /// Event emitted when a claim is transferred to a new owner. [from, to, claim]
+        ClaimTransferred(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when a claim is pruned after its expiry. [owner, claim]
+        ClaimExpired(T::AccountId, Vec<u8>),
+        /// Event emitted when the owner changes the expiry of a claim. [owner, claim, expiry]
+        ClaimRenewed(T::AccountId, Vec<u8>, Option<T::BlockNumber>),
+        /// Event emitted when a Merkle root over a batch of documents is anchored. [who, root, leaf_count]
+        BatchAnchored(T::AccountId, T::Hash, u32),
+        /// Event emitted when a batch is revoked by the owner. [who, root]
+        BatchRevoked(T::AccountId, T::Hash),
+        /// Event emitted when a claim is offered to another account. [from, to, claim]
+        TransferOffered(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when an offer is withdrawn by the owner. [from, to, claim]
+        TransferCancelled(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when an account accepts direct transfers from a sender. [who, sender]
+        SenderApproved(T::AccountId, T::AccountId),
+        /// Event emitted when an account withdraws its approval of a sender. [who, sender]
+        SenderUnapproved(T::AccountId, T::AccountId),
+        /// Event emitted when a co-owner approves a co-signed claim. [who, claim]
+        ClaimApproved(T::AccountId, Vec<u8>),
+        /// Event emitted when a co-signed claim reaches its threshold. [claim]
+        ClaimFinalized(Vec<u8>),
+        /// Event emitted when a co-owner approves an action below the threshold. [who, claim, action]
+        ClaimActionApproved(T::AccountId, Vec<u8>, ClaimAction<T::AccountId, T::BlockNumber>),
+        /// Event emitted when the owner changes the metadata of a claim. [owner, claim]
+        ClaimMetadataUpdated(T::AccountId, Vec<u8>),
+        /// Event emitted when an account commits to a proof without publishing it. [who, commitment]
+        ClaimCommitted(T::AccountId, T::Hash),
+        /// Event emitted when a commitment is revealed as a claim. [who, commitment, claim]
+        ClaimRevealed(T::AccountId, T::Hash, Vec<u8>),
+        /// Event emitted when a commitment is removed after its reveal window. [owner, commitment]
+        CommitmentReaped(T::AccountId, T::Hash),
+        /// Event emitted when root registers a notary. [notary]
+        NotaryAdded(T::AccountId),
+        /// Event emitted when root removes a notary. [notary]
+        NotaryRemoved(T::AccountId),
+        /// Event emitted when a notary attests a claim. [notary, claim]
+        ClaimAttested(T::AccountId, Vec<u8>),
+        /// Event emitted when a claim is challenged. [challenger, claim, evidence]
+        ClaimChallenged(T::AccountId, Vec<u8>, T::Hash),
+        /// Event emitted when a dispute is resolved in favour of the owner. [owner, challenger, claim]
+        DisputeUpheld(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when a dispute is resolved in favour of the challenger. [from, to, claim]
+        ClaimReassigned(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when a dispute is resolved in favour of a challenger who cannot take the claim over. [owner, challenger, claim]
+        ClaimVoided(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when a claim is revoked by `ForceOrigin`. [owner, claim]
+        ClaimForceRevoked(T::AccountId, Vec<u8>),
+        /// Event emitted when a claim is transferred by `ForceOrigin`. [from, to, claim]
+        ClaimForceTransferred(T::AccountId, T::AccountId, Vec<u8>),
+        /// Event emitted when a claim is frozen by `ForceOrigin`. [claim]
+        ClaimFrozen(Vec<u8>),
+        /// Event emitted when a claim is thawed by `ForceOrigin`. [claim]
+        ClaimThawed(Vec<u8>),
+        /// Event emitted when a batch call is rolled back because of one of its items. [index, error]
+        BatchInterrupted(u32, DispatchError),
     }
 
     #[pallet::error]
@@ -46,7 +347,87 @@ pub mod pallet {
         /// The proof is claimed by another account, so caller can't revoke it.
         NotProofOwner,
         /// Invalid metadata given.
-        BadMetadata
+        BadMetadata,
+        /// The account cannot afford the deposit for the claim.
+        InsufficientBalance,
+        /// The account already owns the maximum number of claims.
+        TooManyClaims,
+        /// The expiry is in the past or further away than `MaxClaimDuration`.
+        InvalidExpiry,
+        /// `MaxExpiriesPerBlock` claims already expire at the requested block.
+        ExpiryBlockFull,
+        /// The Merkle root has already been anchored.
+        BatchAlreadyAnchored,
+        /// The Merkle root has not been anchored.
+        NoSuchBatch,
+        /// A batch must contain at least one document.
+        EmptyBatch,
+        /// The inclusion proof does not lead to the anchored root.
+        InvalidMerkleProof,
+        /// The content is longer than `MaxContentLength`.
+        ContentTooLong,
+        /// The recipient has not approved direct transfers from the sender.
+        TransferNotApproved,
+        /// A claim cannot be transferred to its owner.
+        TransferToSelf,
+        /// The claim has not been offered to anyone.
+        NoPendingTransfer,
+        /// The claim has been offered to another account.
+        NotTransferRecipient,
+        /// More co-owners were given than `MaxCoOwners`.
+        TooManyCoOwners,
+        /// The same account was listed twice as a co-owner.
+        DuplicateCoOwner,
+        /// The threshold is zero or larger than the number of co-owners.
+        InvalidThreshold,
+        /// The caller does not co-own the claim.
+        NotCoOwner,
+        /// The caller has already approved.
+        AlreadyApproved,
+        /// Another action awaits the approval of the co-owners.
+        ActionPending,
+        /// The claim needs no further approval.
+        AlreadyFinalized,
+        /// The co-signed claim has not been finalized yet.
+        ClaimNotFinalized,
+        /// The operation is not available for co-signed claims.
+        CoSignedClaim,
+        /// The signature does not match the owner and payload.
+        InvalidSignature,
+        /// The deadline of the signed payload has passed.
+        SignatureExpired,
+        /// The commitment has already been made.
+        CommitmentAlreadyExists,
+        /// No commitment matches the proof, salt and sender.
+        NoSuchCommitment,
+        /// The reveal window of the commitment has passed.
+        RevealWindowClosed,
+        /// The commitment can still be revealed, so it cannot be reaped.
+        RevealWindowOpen,
+        /// The account is already a registered notary.
+        AlreadyNotary,
+        /// The account is not a registered notary.
+        NotNotary,
+        /// `MaxNotaries` notaries are already registered.
+        TooManyNotaries,
+        /// The notary has already attested the claim.
+        AlreadyAttested,
+        /// The claim is disputed, so it cannot change until the dispute is resolved.
+        ClaimDisputed,
+        /// The claim is not disputed.
+        NotDisputed,
+        /// The owner cannot challenge their own claim.
+        ChallengerIsOwner,
+        /// The claim is frozen, so the owner cannot transfer or revoke it.
+        ClaimIsFrozen,
+        /// The claim is already frozen.
+        AlreadyFrozen,
+        /// The claim is not frozen.
+        NotFrozen,
+        /// More items were given than `MaxBatchSize`.
+        BatchTooLarge,
+        /// An item of the batch failed, see the `BatchInterrupted` event for its index and error.
+        BatchItemFailed,
     }
 
     #[pallet::pallet]
@@ -54,72 +435,541 @@ pub mod pallet {
     pub struct Pallet<T>(_);
 
     #[pallet::storage]
-    pub type Proofs<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, (T::AccountId, T::BlockNumber), ValueQuery>;
+    pub type Proofs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        OptionQuery
+    >;
+
+    /// The chain of custody of every claim, oldest owner first, keeping the creator and the latest
+    /// owners up to `MaxHistoryLength` entries.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_history)]
+    pub type ClaimHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        Vec<(T::AccountId, T::BlockNumber)>,
+        ValueQuery
+    >;
+
+    /// The proofs owned by every account, in the order they were acquired.
+    #[pallet::storage]
+    #[pallet::getter(fn claims_by_owner)]
+    pub type ClaimsByOwner<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<Vec<u8>>,
+        ValueQuery
+    >;
+
+    /// The algorithm used by every hashed claim, keyed by its tagged digest.
+    #[pallet::storage]
+    #[pallet::getter(fn proof_algorithm)]
+    pub type ProofAlgorithms<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        HashAlgorithm,
+        OptionQuery
+    >;
+
+    /// The account each claim has been offered to with `offer_claim`.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfer)]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        T::AccountId,
+        OptionQuery
+    >;
+
+    /// The senders each account accepts direct transfers from. [recipient, sender]
+    #[pallet::storage]
+    #[pallet::getter(fn is_sender_approved)]
+    pub type TransferApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery
+    >;
+
+    /// The co-owners and approvals of co-signed claims.
+    #[pallet::storage]
+    #[pallet::getter(fn co_signed_claim)]
+    pub type CoSignedClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        CoSignInfo<T::AccountId>,
+        OptionQuery
+    >;
+
+    /// The action on a co-signed claim awaiting approval, and the co-owners which approved it.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_action)]
+    pub type PendingActions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        (ClaimAction<T::AccountId, T::BlockNumber>, Vec<T::AccountId>),
+        OptionQuery
+    >;
+
+    /// The metadata attached to claims.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_metadata)]
+    pub type ClaimMetadataOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        ClaimMetadata,
+        OptionQuery
+    >;
+
+    /// The anchored Merkle roots of document batches.
+    #[pallet::storage]
+    #[pallet::getter(fn batches)]
+    pub type Batches<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BatchInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        OptionQuery
+    >;
+
+    /// The salted commitments to proofs which have not been revealed yet.
+    #[pallet::storage]
+    #[pallet::getter(fn commitments)]
+    pub type Commitments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        CommitmentInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        OptionQuery
+    >;
+
+    /// The notaries registered by root.
+    #[pallet::storage]
+    #[pallet::getter(fn is_notary)]
+    pub type Notaries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery
+    >;
+
+    /// The number of registered notaries.
+    #[pallet::storage]
+    #[pallet::getter(fn notary_count)]
+    pub type NotaryCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// The notaries which attested every claim, in the order of their attestations.
+    #[pallet::storage]
+    #[pallet::getter(fn attestations)]
+    pub type Attestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        Vec<T::AccountId>,
+        ValueQuery
+    >;
+
+    /// The open disputes over claims.
+    #[pallet::storage]
+    #[pallet::getter(fn dispute)]
+    pub type Disputes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        DisputeInfo<T::AccountId, BalanceOf<T>, T::Hash>,
+        OptionQuery
+    >;
+
+    /// The claims frozen by `ForceOrigin`.
+    #[pallet::storage]
+    #[pallet::getter(fn is_frozen)]
+    pub type FrozenClaims<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        bool,
+        ValueQuery
+    >;
+
+    /// The number of claims created on behalf of every account with `create_claim_for`.
+    #[pallet::storage]
+    #[pallet::getter(fn nonce_of)]
+    pub type Nonces<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery
+    >;
+
+    /// The account which paid the deposit of a claim created with `create_claim_for`, and gets
+    /// it back when the claim is removed.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_depositor)]
+    pub type ClaimDepositors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        T::AccountId,
+        OptionQuery
+    >;
+
+    /// The proofs due to expire at a given block, at most `MaxExpiriesPerBlock` of them.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_expiries)]
+    pub type ClaimExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        T::BlockNumber,
+        Vec<Vec<u8>>,
+        ValueQuery
+    >;
+
+    /// The storage layout currently in use.
+    #[pallet::storage]
+    pub type StorageVersion<T: Config> = StorageValue<_, Releases, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// The proofs claimed at genesis and their owners, who pay the usual deposit.
+        pub claims: Vec<(Vec<u8>, T::AccountId)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { claims: Default::default() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            // New chains start with the latest storage layout.
+            StorageVersion::<T>::put(Releases::V2_0_0);
+
+            for (proof, owner) in &self.claims {
+                assert!(
+                    proof.len() <= T::StringLimit::get() as usize,
+                    "genesis claim is longer than StringLimit"
+                );
+                assert!(!Proofs::<T>::contains_key(proof), "duplicate claim in genesis");
+
+                Pallet::<T>::insert_claim(owner, proof.clone(), Zero::zero(), 0, None)
+                    .expect("genesis claim owners can afford their claims");
+            }
+        }
+    }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Prune the claims expiring at block `n`.
+        ///
+        /// Scheduling keeps at most `MaxExpiriesPerBlock` claims per block, so all of them are pruned.
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            if !ClaimExpiries::<T>::contains_key(n) {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let due = ClaimExpiries::<T>::take(n);
+
+            let mut pruned: Weight = 0;
+            for proof in due.iter() {
+                let claim = match Proofs::<T>::get(proof) {
+                    Some(claim) => claim,
+                    None => continue,
+                };
+                // The claim may have been renewed since it was scheduled.
+                if !matches!(claim.expiry, Some(expiry) if expiry <= n) {
+                    continue;
+                }
+                // Disputed and frozen claims are rescheduled once they are resolved or thawed.
+                if Disputes::<T>::contains_key(proof) || FrozenClaims::<T>::get(proof) {
+                    continue;
+                }
+                Self::remove_claim(proof, &claim);
+                Self::deposit_event(Event::ClaimExpired(claim.owner, proof.clone()));
+                pruned += 1;
+            }
+
+            T::DbWeight::get().reads_writes(
+                2 + due.len() as Weight * 3 + pruned * 3,
+                2 + pruned * 4,
+            )
+        }
+
+        fn integrity_test() {
+            // Hashed claims are stored under their tagged digest like any other proof.
+            assert!(
+                T::StringLimit::get() >= HASHED_PROOF_LENGTH,
+                "StringLimit is shorter than the key of a hashed claim"
+            );
+            // The chain of custody always keeps its creator next to the current owner.
+            assert!(
+                T::MaxHistoryLength::get() >= 2,
+                "MaxHistoryLength cannot hold both the creator and the owner of a claim"
+            );
+            // Expired claims are rescheduled to the first block with room left.
+            assert!(T::MaxExpiriesPerBlock::get() > 0, "MaxExpiriesPerBlock leaves no room for expiring claims");
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            crate::migrations::migrate_to_v2::<T>()
+        }
+    }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
     // These functions materialize as "extrinsics", which are often compared to transactions.
     // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(1_000)]
+        #[pallet::weight(T::WeightInfo::create_claim(
+            proof.len() as u32,
+            metadata.as_ref().map_or(0, |metadata| metadata.encoded_size() as u32),
+        ))]
         pub(super) fn create_claim(
             origin: OriginFor<T>,
             proof: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+            metadata: Option<ClaimMetadata>,
         ) -> DispatchResultWithPostInfo {
-            // Limited in length by `StringLimit`
-            ensure!(proof.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
             // Check that the extrinsic was signed and get the signer.
             // This function will return an error if the extrinsic is not signed.
             // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
 
-            // Verify that the specified proof has not already been claimed.
-            ensure!(!Proofs::<T>::contains_key(&proof), Error::<T>::ProofAlreadyClaimed);
+            // Limited in duration by `MaxClaimDuration`
+            Self::ensure_valid_expiry(expiry)?;
+
+            if let Some(metadata) = &metadata {
+                Self::ensure_valid_metadata(metadata)?;
+            }
+
+            // The deposits of the claim and of its metadata are reserved together.
+            let metadata_deposit = metadata.as_ref().map_or_else(Zero::zero, Self::metadata_deposit);
+            ensure!(
+                T::Currency::can_reserve(&sender, Self::claim_deposit(&proof).saturating_add(metadata_deposit)),
+                Error::<T>::InsufficientBalance
+            );
 
             // Get the block number from the FRAME System module.
             let current_block = <frame_system::Module<T>>::block_number();
 
             // Store the proof with the sender and block number.
-            Proofs::<T>::insert(&proof, (&sender, current_block));
+            Self::insert_claim(&sender, proof.clone(), current_block, Self::now(), expiry)?;
 
-            // Emit an event that the claim was created.
-            Self::deposit_event(Event::ClaimCreated(sender, proof));
+            if metadata.is_some() {
+                Self::update_metadata(&proof, metadata)?;
+            }
 
             Ok(().into())
         }
 
-        #[pallet::weight(10_000)]
-        pub fn revoke_claim(
+        /// Claim every proof in `proofs`.
+        ///
+        /// Either all proofs are claimed or none is. If an item fails, `BatchInterrupted` reports
+        /// its index and error.
+        #[pallet::weight(T::WeightInfo::create_claims(proofs.len() as u32))]
+        pub fn create_claims(
+            origin: OriginFor<T>,
+            proofs: Vec<Vec<u8>>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(proofs.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+
+            let current_block = <frame_system::Module<T>>::block_number();
+            let now = Self::now();
+
+            Self::do_batch(proofs, |proof| Self::insert_claim(&sender, proof, current_block, now, None))
+        }
+
+        /// Revoke every proof in `proofs`, all or nothing.
+        #[pallet::weight(T::WeightInfo::revoke_claims(proofs.len() as u32))]
+        pub fn revoke_claims(
+            origin: OriginFor<T>,
+            proofs: Vec<Vec<u8>>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(proofs.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+
+            Self::do_batch(proofs, |proof| Self::do_revoke(sender.clone(), proof))
+        }
+
+        /// Transfer every proof to its paired account, all or nothing.
+        #[pallet::weight(T::WeightInfo::transfer_claims(transfers.len() as u32))]
+        pub fn transfer_claims(
+            origin: OriginFor<T>,
+            transfers: Vec<(Vec<u8>, T::AccountId)>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(transfers.len() <= T::MaxBatchSize::get() as usize, Error::<T>::BatchTooLarge);
+
+            Self::do_batch(transfers, |(proof, dist)| Self::do_transfer_claim(sender.clone(), proof, dist))
+        }
+
+        /// Claim a proof on behalf of `owner`, who signed it off-chain.
+        ///
+        /// `signature` is the owner's signature of [`Pallet::delegated_claim_payload`] over the
+        /// proof, expiry, the owner's current nonce and `deadline`. The sender pays the fees and
+        /// the deposit, which is reserved from the sender and refunded to it when the claim is
+        /// removed.
+        #[pallet::weight(T::WeightInfo::create_claim_for(proof.len() as u32))]
+        pub fn create_claim_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            proof: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+            deadline: T::BlockNumber,
+            signature: T::Signature,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+
+            let current_block = <frame_system::Module<T>>::block_number();
+            ensure!(current_block <= deadline, Error::<T>::SignatureExpired);
+
+            let nonce = Nonces::<T>::get(&owner);
+            let payload = Self::delegated_claim_payload(&proof, expiry, nonce, deadline);
+            ensure!(signature.verify(&payload[..], &owner), Error::<T>::InvalidSignature);
+
+            Self::ensure_valid_expiry(expiry)?;
+            Self::ensure_claimable(&owner, &proof)?;
+            ensure!(
+                T::Currency::can_reserve(&relayer, Self::claim_deposit(&proof)),
+                Error::<T>::InsufficientBalance
+            );
+
+            // The signature cannot be replayed.
+            Nonces::<T>::insert(&owner, nonce + 1);
+
+            // The owner may hold no funds, so the deposit is reserved from the relayer.
+            Self::insert_claim_paid_by(&owner, &relayer, proof, current_block, Self::now(), expiry)?;
+
+            Ok(().into())
+        }
+
+        /// Claim a proof on behalf of the sender and `co_owners`.
+        ///
+        /// The sender is added to the co-owners and counts as the first approval. The claim is
+        /// finalized once `threshold` co-owners have approved it with `approve_claim`, and can
+        /// only be revoked or transferred once `threshold` co-owners asked for it.
+        #[pallet::weight(T::WeightInfo::create_cosigned_claim(
+            proof.len() as u32,
+            co_owners.len() as u32 + 1,
+        ))]
+        pub fn create_cosigned_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            co_owners: Vec<T::AccountId>,
+            threshold: u32,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let mut co_owners = co_owners;
+            if !co_owners.contains(&sender) {
+                co_owners.insert(0, sender.clone());
+            }
+            ensure!(co_owners.len() <= T::MaxCoOwners::get() as usize, Error::<T>::TooManyCoOwners);
+
+            let mut distinct = co_owners.clone();
+            distinct.sort();
+            distinct.dedup();
+            ensure!(distinct.len() == co_owners.len(), Error::<T>::DuplicateCoOwner);
+
+            ensure!(
+                threshold > 0 && threshold as usize <= co_owners.len(),
+                Error::<T>::InvalidThreshold
+            );
+
+            Self::ensure_valid_expiry(expiry)?;
+
+            let current_block = <frame_system::Module<T>>::block_number();
+            Self::insert_claim(&sender, proof.clone(), current_block, Self::now(), expiry)?;
+
+            let status = if threshold == 1 { ClaimStatus::Finalized } else { ClaimStatus::Pending };
+            CoSignedClaims::<T>::insert(&proof, CoSignInfo {
+                co_owners,
+                threshold,
+                approvals: vec![sender],
+                status,
+            });
+
+            if status == ClaimStatus::Finalized {
+                Self::deposit_event(Event::ClaimFinalized(proof));
+            }
+
+            Ok(().into())
+        }
+
+        /// Approve a co-signed claim as one of its co-owners.
+        #[pallet::weight(T::WeightInfo::approve_claim(T::MaxCoOwners::get()))]
+        pub fn approve_claim(
             origin: OriginFor<T>,
             proof: Vec<u8>,
         ) -> DispatchResultWithPostInfo {
-            // Check that the extrinsic was signed and get the signer.
-            // This function will return an error if the extrinsic is not signed.
-            // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
 
-            // Verify that the specified proof has been claimed.
             ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
 
-            // Get owner of the claim.
-            let (owner, _) = Proofs::<T>::get(&proof);
+            // Single-owner claims are final from the start.
+            let mut info = CoSignedClaims::<T>::get(&proof).ok_or(Error::<T>::AlreadyFinalized)?;
 
-            // Verify that sender of the current call is the claim owner.
-            ensure!(sender == owner, Error::<T>::NotProofOwner);
+            ensure!(info.status == ClaimStatus::Pending, Error::<T>::AlreadyFinalized);
+            ensure!(info.co_owners.contains(&sender), Error::<T>::NotCoOwner);
+            ensure!(!info.approvals.contains(&sender), Error::<T>::AlreadyApproved);
 
-            // Remove claim from storage.
-            Proofs::<T>::remove(&proof);
+            info.approvals.push(sender.clone());
+            let finalized = info.approvals.len() as u32 >= info.threshold;
+            if finalized {
+                info.status = ClaimStatus::Finalized;
+            }
+            CoSignedClaims::<T>::insert(&proof, info);
 
-            // Emit an event that the claim was erased.
-            Self::deposit_event(Event::ClaimRevoked(sender, proof));
+            Self::deposit_event(Event::ClaimApproved(sender, proof.clone()));
+            if finalized {
+                Self::deposit_event(Event::ClaimFinalized(proof));
+            }
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(T::WeightInfo::revoke_claim(proof.len() as u32))]
+        pub fn revoke_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            // Check that the extrinsic was signed and get the signer.
+            // This function will return an error if the extrinsic is not signed.
+            // https://substrate.dev/docs/en/knowledgebase/runtime/origin
+            let sender = ensure_signed(origin)?;
+
+            Self::do_revoke(sender, proof)?;
 
             Ok(().into())
         }
 
-        #[pallet::weight(10_000)]
+        /// Transfer a claim directly to `dist`.
+        ///
+        /// `dist` must have approved the sender with `approve_sender`, otherwise the claim has to
+        /// be handed over with `offer_claim` and `accept_claim`.
+        #[pallet::weight(T::WeightInfo::transfer_claim(proof.len() as u32))]
         pub fn transfer_claim(
             origin: OriginFor<T>,
             proof: Vec<u8>,
@@ -130,16 +980,1169 @@ pub mod pallet {
             // https://substrate.dev/docs/en/knowledgebase/runtime/origin
             let sender = ensure_signed(origin)?;
 
-            // Verify that the specified proof has been claimed.
-            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+            Self::do_transfer_claim(sender, proof, dist)?;
+
+            Ok(().into())
+        }
+
+        /// Offer a claim to `to`, who has to accept it with `accept_claim`.
+        ///
+        /// A new offer replaces any offer of the claim which has not been accepted yet.
+        #[pallet::weight(T::WeightInfo::offer_claim(proof.len() as u32))]
+        pub fn offer_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            to: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+            // Co-signed claims change hands through `transfer_claim` only.
+            ensure!(!CoSignedClaims::<T>::contains_key(&proof), Error::<T>::CoSignedClaim);
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            ensure!(to != sender, Error::<T>::TransferToSelf);
+
+            PendingTransfers::<T>::insert(&proof, &to);
+
+            Self::deposit_event(Event::TransferOffered(sender, to, proof));
+
+            Ok(().into())
+        }
 
-            let (owner, _block_number) = Proofs::<T>::get(&proof);
+        /// Accept a claim offered to the sender.
+        #[pallet::weight(T::WeightInfo::accept_claim(proof.len() as u32))]
+        pub fn accept_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let to = PendingTransfers::<T>::get(&proof).ok_or(Error::<T>::NoPendingTransfer)?;
+
+            ensure!(to == sender, Error::<T>::NotTransferRecipient);
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+
+            // Offers are dropped together with their claim, so the claim exists.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            Self::do_transfer(claim, sender, proof)?;
+
+            Ok(().into())
+        }
+
+        /// Withdraw an offer which has not been accepted yet.
+        #[pallet::weight(T::WeightInfo::cancel_offer(proof.len() as u32))]
+        pub fn cancel_offer(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let to = PendingTransfers::<T>::get(&proof).ok_or(Error::<T>::NoPendingTransfer)?;
+
+            ensure!(
+                Proofs::<T>::get(&proof).map_or(false, |claim| claim.owner == sender),
+                Error::<T>::NotProofOwner
+            );
+
+            PendingTransfers::<T>::remove(&proof);
+
+            Self::deposit_event(Event::TransferCancelled(sender, to, proof));
+
+            Ok(().into())
+        }
+
+        /// Allow `sender` to transfer claims directly to the caller.
+        #[pallet::weight(T::WeightInfo::approve_sender())]
+        pub fn approve_sender(
+            origin: OriginFor<T>,
+            sender: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            TransferApprovals::<T>::insert(&who, &sender, true);
+
+            Self::deposit_event(Event::SenderApproved(who, sender));
+
+            Ok(().into())
+        }
+
+        /// Withdraw the approval given to `sender` with `approve_sender`.
+        #[pallet::weight(T::WeightInfo::unapprove_sender())]
+        pub fn unapprove_sender(
+            origin: OriginFor<T>,
+            sender: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            TransferApprovals::<T>::remove(&who, &sender);
+
+            Self::deposit_event(Event::SenderUnapproved(who, sender));
+
+            Ok(().into())
+        }
+
+        /// Change the expiry of a claim, or make it permanent with `None`.
+        ///
+        /// The expiry of a co-signed claim changes once `threshold` co-owners asked for it.
+        #[pallet::weight(T::WeightInfo::renew_claim(proof.len() as u32))]
+        pub fn renew_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            Self::ensure_valid_expiry(expiry)?;
+
+            let mut claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // An expiry would let the owner drop a disputed or frozen claim with a full refund.
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+            // A claim renewed to its current expiry keeps its slot.
+            if let Some(new_expiry) = expiry.filter(|_| expiry != claim.expiry) {
+                Self::ensure_expiry_slot(new_expiry)?;
+            }
+
+            if let Some(info) = CoSignedClaims::<T>::get(&proof) {
+                if !Self::approve_action(&sender, &proof, &info, ClaimAction::Renew(expiry))? {
+                    return Ok(().into());
+                }
+                PendingActions::<T>::remove(&proof);
+            } else {
+                ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+            }
 
-            ensure!(owner == sender, Error::<T>::NotProofOwner);
+            // Move the claim from its current expiry slot to the new one.
+            if let Some(old_expiry) = claim.expiry {
+                Self::remove_from_expiries(old_expiry, &proof);
+            }
+            if let Some(new_expiry) = expiry {
+                ClaimExpiries::<T>::append(new_expiry, &proof);
+            }
 
-            Proofs::<T>::insert(&proof, (dist, <frame_system::Module<T>>::block_number()));
+            claim.expiry = expiry;
+            let owner = claim.owner.clone();
+            Proofs::<T>::insert(&proof, claim);
+
+            Self::deposit_event(Event::ClaimRenewed(owner, proof, expiry));
+
+            Ok(().into())
+        }
+
+        /// Replace the metadata of a claim, or clear it with `None`.
+        ///
+        /// The deposit of the claim follows the encoded length of its metadata: the owner reserves
+        /// the difference when it grows and is refunded when it shrinks.
+        #[pallet::weight(T::WeightInfo::set_claim_metadata(
+            proof.len() as u32,
+            metadata.as_ref().map_or(0, |metadata| metadata.encoded_size() as u32),
+        ))]
+        pub fn set_claim_metadata(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            metadata: Option<ClaimMetadata>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+
+            if let Some(metadata) = &metadata {
+                Self::ensure_valid_metadata(metadata)?;
+            }
+
+            Self::update_metadata(&proof, metadata)?;
+
+            Self::deposit_event(Event::ClaimMetadataUpdated(sender, proof));
 
             Ok(().into())
         }
+
+        /// Anchor the Merkle root over the hashes of a batch of `leaf_count` documents.
+        ///
+        /// Individual documents can later be proven with [`Pallet::verify_inclusion`] or
+        /// recorded as claims of their own with `claim_from_batch`.
+        #[pallet::weight(T::WeightInfo::anchor_batch())]
+        pub fn anchor_batch(
+            origin: OriginFor<T>,
+            root: T::Hash,
+            leaf_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(leaf_count > 0, Error::<T>::EmptyBatch);
+            ensure!(!Batches::<T>::contains_key(&root), Error::<T>::BatchAlreadyAnchored);
+
+            // A root is charged like a claim of the same length.
+            let deposit = Self::claim_deposit(root.as_ref());
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            Batches::<T>::insert(&root, BatchInfo {
+                owner: sender.clone(),
+                block_number: <frame_system::Module<T>>::block_number(),
+                moment: Self::now(),
+                leaf_count,
+                deposit,
+            });
+
+            Self::deposit_event(Event::BatchAnchored(sender, root, leaf_count));
+
+            Ok(().into())
+        }
+
+        /// Remove an anchored root and refund its deposit.
+        ///
+        /// Claims already recorded from the batch are kept.
+        #[pallet::weight(T::WeightInfo::revoke_batch())]
+        pub fn revoke_batch(
+            origin: OriginFor<T>,
+            root: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let batch = Batches::<T>::get(&root).ok_or(Error::<T>::NoSuchBatch)?;
+
+            ensure!(batch.owner == sender, Error::<T>::NotProofOwner);
+
+            Batches::<T>::remove(&root);
+            T::Currency::unreserve(&sender, batch.deposit);
+
+            Self::deposit_event(Event::BatchRevoked(sender, root));
+
+            Ok(().into())
+        }
+
+        /// Record a document of an anchored batch as a claim of its own.
+        ///
+        /// The claim is dated at the block the batch was anchored.
+        #[pallet::weight(T::WeightInfo::claim_from_batch(proof.len() as u32))]
+        pub fn claim_from_batch(
+            origin: OriginFor<T>,
+            root: T::Hash,
+            leaf: T::Hash,
+            proof: Vec<T::Hash>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let batch = Batches::<T>::get(&root).ok_or(Error::<T>::NoSuchBatch)?;
+
+            ensure!(batch.owner == sender, Error::<T>::NotProofOwner);
+            ensure!(Self::verify_inclusion(root, leaf, &proof), Error::<T>::InvalidMerkleProof);
+
+            Self::insert_claim(&sender, leaf.as_ref().to_vec(), batch.block_number, batch.moment, None)?;
+
+            Ok(().into())
+        }
+
+        /// Commit to a proof without publishing it.
+        ///
+        /// `commitment` is [`Pallet::commitment_of`] the proof, a secret salt and the sender. The
+        /// proof can be revealed with `reveal_claim` within `RevealWindow` blocks, and the claim
+        /// is then dated at the block of the commitment.
+        #[pallet::weight(T::WeightInfo::commit_claim())]
+        pub fn commit_claim(
+            origin: OriginFor<T>,
+            commitment: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(!Commitments::<T>::contains_key(&commitment), Error::<T>::CommitmentAlreadyExists);
+
+            // A commitment is charged like a claim of the same length.
+            let deposit = Self::claim_deposit(commitment.as_ref());
+            T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            Commitments::<T>::insert(&commitment, CommitmentInfo {
+                owner: sender.clone(),
+                block_number: <frame_system::Module<T>>::block_number(),
+                moment: Self::now(),
+                deposit,
+            });
+
+            Self::deposit_event(Event::ClaimCommitted(sender, commitment));
+
+            Ok(().into())
+        }
+
+        /// Reveal the proof and salt of a commitment made by the sender and claim the proof.
+        #[pallet::weight(T::WeightInfo::reveal_claim(proof.len() as u32, salt.len() as u32))]
+        pub fn reveal_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            salt: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let commitment = Self::commitment_of(&proof, &salt, &sender);
+            let commit = Commitments::<T>::get(&commitment).ok_or(Error::<T>::NoSuchCommitment)?;
+
+            // The commitment must have been made by the sender, not copied by another account.
+            ensure!(commit.owner == sender, Error::<T>::NoSuchCommitment);
+
+            let current_block = <frame_system::Module<T>>::block_number();
+            ensure!(
+                current_block <= commit.block_number.saturating_add(T::RevealWindow::get()),
+                Error::<T>::RevealWindowClosed
+            );
+
+            Self::ensure_claimable(&sender, &proof)?;
+
+            // The commitment deposit is refunded before the claim deposit is reserved.
+            ensure!(
+                T::Currency::can_reserve(&sender, Self::claim_deposit(&proof).saturating_sub(commit.deposit)),
+                Error::<T>::InsufficientBalance
+            );
+            T::Currency::unreserve(&commit.owner, commit.deposit);
+            Commitments::<T>::remove(&commitment);
+
+            Self::insert_claim(&sender, proof.clone(), commit.block_number, commit.moment, None)?;
+
+            Self::deposit_event(Event::ClaimRevealed(sender, commitment, proof));
+
+            Ok(().into())
+        }
+
+        /// Remove a commitment which was not revealed within `RevealWindow` blocks and refund
+        /// its deposit to the owner.
+        ///
+        /// Any account may reap a commitment.
+        #[pallet::weight(T::WeightInfo::reap_commitment())]
+        pub fn reap_commitment(
+            origin: OriginFor<T>,
+            commitment: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let commit = Commitments::<T>::get(&commitment).ok_or(Error::<T>::NoSuchCommitment)?;
+
+            let current_block = <frame_system::Module<T>>::block_number();
+            ensure!(
+                current_block > commit.block_number.saturating_add(T::RevealWindow::get()),
+                Error::<T>::RevealWindowOpen
+            );
+
+            Commitments::<T>::remove(&commitment);
+            T::Currency::unreserve(&commit.owner, commit.deposit);
+
+            Self::deposit_event(Event::CommitmentReaped(commit.owner, commitment));
+
+            Ok(().into())
+        }
+
+        /// Register `notary` as an account which may attest claims.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        #[pallet::weight(T::WeightInfo::add_notary())]
+        pub fn add_notary(
+            origin: OriginFor<T>,
+            notary: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(!Notaries::<T>::get(&notary), Error::<T>::AlreadyNotary);
+            let count = NotaryCount::<T>::get();
+            ensure!(count < T::MaxNotaries::get(), Error::<T>::TooManyNotaries);
+
+            Notaries::<T>::insert(&notary, true);
+            NotaryCount::<T>::put(count + 1);
+
+            Self::deposit_event(Event::NotaryAdded(notary));
+
+            Ok(().into())
+        }
+
+        /// Remove `notary` from the registry.
+        ///
+        /// Its attestations are kept but no longer count towards verification.
+        /// The dispatch origin for this call must be _Root_.
+        #[pallet::weight(T::WeightInfo::remove_notary())]
+        pub fn remove_notary(
+            origin: OriginFor<T>,
+            notary: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(Notaries::<T>::get(&notary), Error::<T>::NotNotary);
+
+            Notaries::<T>::remove(&notary);
+            NotaryCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::NotaryRemoved(notary));
+
+            Ok(().into())
+        }
+
+        /// Attest a claim as a registered notary.
+        ///
+        /// The claim is verified once `AttestationThreshold` registered notaries attested it.
+        #[pallet::weight(T::WeightInfo::attest_claim(proof.len() as u32))]
+        pub fn attest_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Notaries::<T>::get(&sender), Error::<T>::NotNotary);
+            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+
+            let mut attestations = Attestations::<T>::get(&proof);
+            ensure!(!attestations.contains(&sender), Error::<T>::AlreadyAttested);
+
+            attestations.push(sender.clone());
+            Attestations::<T>::insert(&proof, attestations);
+
+            Self::deposit_event(Event::ClaimAttested(sender, proof));
+
+            Ok(().into())
+        }
+
+        /// Challenge the owner of a claim, reserving `ChallengeBond` from the sender.
+        ///
+        /// The claim cannot be transferred or revoked until root resolves the dispute with
+        /// `resolve_dispute`.
+        #[pallet::weight(T::WeightInfo::challenge_claim(proof.len() as u32))]
+        pub fn challenge_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            evidence: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            ensure!(claim.owner != sender, Error::<T>::ChallengerIsOwner);
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+
+            let bond = T::ChallengeBond::get();
+            T::Currency::reserve(&sender, bond).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            Disputes::<T>::insert(&proof, DisputeInfo {
+                challenger: sender.clone(),
+                evidence,
+                bond,
+            });
+
+            Self::deposit_event(Event::ClaimChallenged(sender, proof, evidence));
+
+            Ok(().into())
+        }
+
+        /// Resolve the dispute over a claim.
+        ///
+        /// If `uphold` is set the owner keeps the claim and the bond of the challenger is
+        /// slashed. Otherwise the claim is reassigned to the challenger with its original date,
+        /// the deposit of the owner is slashed and the bond of the challenger becomes the deposit
+        /// of the claim. If the challenger cannot take the claim over because of
+        /// `MaxClaimsPerAccount`, the claim is removed instead, the deposit of the owner slashed
+        /// and the bond refunded.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        #[pallet::weight(T::WeightInfo::resolve_dispute(proof.len() as u32))]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            uphold: bool,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let dispute = Disputes::<T>::get(&proof).ok_or(Error::<T>::NotDisputed)?;
+
+            // Disputes are dropped together with their claim, so the claim exists.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            if uphold {
+                Disputes::<T>::remove(&proof);
+                T::Currency::slash_reserved(&dispute.challenger, dispute.bond);
+                Self::reschedule_if_expired(&proof, claim.expiry);
+
+                Self::deposit_event(Event::DisputeUpheld(claim.owner, dispute.challenger, proof));
+
+                return Ok(().into());
+            }
+
+            T::Currency::slash_reserved(&Self::deposit_holder(&proof, &claim.owner), claim.deposit);
+
+            let mut challenger_owned = ClaimsByOwner::<T>::get(&dispute.challenger);
+            if challenger_owned.len() >= T::MaxClaimsPerAccount::get() as usize {
+                Self::remove_claim(&proof, &ClaimInfo { deposit: Zero::zero(), ..claim.clone() });
+
+                Self::deposit_event(Event::ClaimVoided(claim.owner, dispute.challenger, proof));
+
+                return Ok(().into());
+            }
+
+            Disputes::<T>::remove(&proof);
+            ClaimDepositors::<T>::remove(&proof);
+
+            Proofs::<T>::insert(&proof, ClaimInfo {
+                owner: dispute.challenger.clone(),
+                deposit: dispute.bond,
+                ..claim.clone()
+            });
+
+            Self::record_owner(&proof, &dispute.challenger, <frame_system::Module<T>>::block_number());
+
+            Self::remove_from_owner(&claim.owner, &proof);
+            challenger_owned.push(proof.clone());
+            ClaimsByOwner::<T>::insert(&dispute.challenger, challenger_owned);
+
+            // Offers and approvals were made by the previous owner.
+            PendingTransfers::<T>::remove(&proof);
+            CoSignedClaims::<T>::remove(&proof);
+            PendingActions::<T>::remove(&proof);
+
+            Self::reschedule_if_expired(&proof, claim.expiry);
+
+            Self::deposit_event(Event::ClaimReassigned(claim.owner, dispute.challenger, proof));
+
+            Ok(().into())
+        }
+
+        /// Revoke a claim regardless of its owner and refund the deposit.
+        ///
+        /// The dispatch origin for this call must be `ForceOrigin`.
+        #[pallet::weight(T::WeightInfo::force_revoke(proof.len() as u32))]
+        pub fn force_revoke(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            Self::remove_claim(&proof, &claim);
+
+            Self::deposit_event(Event::ClaimForceRevoked(claim.owner, proof));
+
+            Ok(().into())
+        }
+
+        /// Transfer a claim to `dist` regardless of its owner and the approvals of `dist`.
+        ///
+        /// An open dispute is dropped and the bond of its challenger refunded.
+        /// `ClaimForceTransferred` is emitted after the regular `ClaimTransferred`.
+        /// The dispatch origin for this call must be `ForceOrigin`.
+        #[pallet::weight(T::WeightInfo::force_transfer(proof.len() as u32))]
+        pub fn force_transfer(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            dist: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+            let from = claim.owner.clone();
+            let expiry = claim.expiry;
+            Self::do_transfer(claim, dist.clone(), proof.clone())?;
+
+            if let Some(dispute) = Disputes::<T>::take(&proof) {
+                T::Currency::unreserve(&dispute.challenger, dispute.bond);
+                Self::reschedule_if_expired(&proof, expiry);
+            }
+
+            Self::deposit_event(Event::ClaimForceTransferred(from, dist, proof));
+
+            Ok(().into())
+        }
+
+        /// Freeze a claim so that its owner can no longer transfer, revoke or renew it or change
+        /// its metadata.
+        ///
+        /// A frozen claim does not expire until it is thawed.
+        /// The dispatch origin for this call must be `ForceOrigin`.
+        #[pallet::weight(T::WeightInfo::freeze_claim(proof.len() as u32))]
+        pub fn freeze_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::AlreadyFrozen);
+
+            FrozenClaims::<T>::insert(&proof, true);
+
+            Self::deposit_event(Event::ClaimFrozen(proof));
+
+            Ok(().into())
+        }
+
+        /// Lift the freeze of a claim.
+        ///
+        /// A claim which expired while frozen is pruned in the next block.
+        /// The dispatch origin for this call must be `ForceOrigin`.
+        #[pallet::weight(T::WeightInfo::thaw_claim(proof.len() as u32))]
+        pub fn thaw_claim(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            ensure!(FrozenClaims::<T>::get(&proof), Error::<T>::NotFrozen);
+
+            FrozenClaims::<T>::remove(&proof);
+            if let Some(claim) = Proofs::<T>::get(&proof) {
+                Self::reschedule_if_expired(&proof, claim.expiry);
+            }
+
+            Self::deposit_event(Event::ClaimThawed(proof));
+
+            Ok(().into())
+        }
+
+        /// Claim a fixed-size digest computed off-chain with the given `algorithm`.
+        ///
+        /// The claim is keyed by the digest tagged with the algorithm, see [`HashAlgorithm::tagged`].
+        #[pallet::weight(T::WeightInfo::create_hashed_claim())]
+        pub fn create_hashed_claim(
+            origin: OriginFor<T>,
+            algorithm: HashAlgorithm,
+            digest: [u8; 32],
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            Self::insert_hashed_claim(&sender, algorithm, digest, expiry)?;
+
+            Ok(().into())
+        }
+
+        /// Hash `content` on-chain with the given `algorithm` and claim the digest.
+        ///
+        /// The content is limited in length by `MaxContentLength`.
+        #[pallet::weight(T::WeightInfo::hash_and_claim(content.len() as u32))]
+        pub fn hash_and_claim(
+            origin: OriginFor<T>,
+            algorithm: HashAlgorithm,
+            content: Vec<u8>,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(content.len() <= T::MaxContentLength::get() as usize, Error::<T>::ContentTooLong);
+
+            Self::insert_hashed_claim(&sender, algorithm, algorithm.hash(&content), expiry)?;
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The current Unix time in milliseconds.
+        pub fn now() -> u64 {
+            T::UnixTime::now().as_millis() as u64
+        }
+
+        /// The deposit reserved for claiming `proof`.
+        pub fn claim_deposit(proof: &[u8]) -> BalanceOf<T> {
+            let bytes: BalanceOf<T> = (proof.len() as u32).into();
+            T::ClaimDepositBase::get()
+                .saturating_add(T::ClaimDepositPerByte::get().saturating_mul(bytes))
+        }
+
+        /// The proofs owned by `owner`, `CLAIMS_PAGE_SIZE` at a time starting from `page` 0.
+        pub fn claims_of(owner: &T::AccountId, page: u32) -> Vec<Vec<u8>> {
+            let start = page.saturating_mul(CLAIMS_PAGE_SIZE) as usize;
+            ClaimsByOwner::<T>::get(owner)
+                .into_iter()
+                .skip(start)
+                .take(CLAIMS_PAGE_SIZE as usize)
+                .collect()
+        }
+
+        /// Whether `leaf` is part of the anchored batch with the given `root`.
+        ///
+        /// `proof` lists the sibling nodes from the leaf up to the root. The leaf is hashed as
+        /// `MERKLE_LEAF_PREFIX || leaf` and every pair of nodes as `MERKLE_NODE_PREFIX || left ||
+        /// right` in ascending order, so an inner node cannot pass as a document and no left/right
+        /// position has to be supplied.
+        pub fn verify_inclusion(root: T::Hash, leaf: T::Hash, proof: &[T::Hash]) -> bool {
+            let batch = match Batches::<T>::get(&root) {
+                Some(batch) => batch,
+                None => return false,
+            };
+            if proof.len() as u32 > Self::merkle_depth(batch.leaf_count) {
+                return false;
+            }
+            let leaf = T::Hashing::hash_of(&(MERKLE_LEAF_PREFIX, leaf));
+            let computed = proof.iter().fold(leaf, |node, sibling| {
+                if node <= *sibling {
+                    T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, node, *sibling))
+                } else {
+                    T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, *sibling, node))
+                }
+            });
+            computed == root
+        }
+
+        /// The depth of a Merkle tree with `leaf_count` leaves.
+        fn merkle_depth(leaf_count: u32) -> u32 {
+            match leaf_count {
+                0 | 1 => 0,
+                n => MAX_MERKLE_DEPTH - (n - 1).leading_zeros(),
+            }
+        }
+
+        /// Apply `f` to every item in a storage transaction which is rolled back as a whole if an
+        /// item fails.
+        fn do_batch<I>(
+            items: Vec<I>,
+            mut f: impl FnMut(I) -> DispatchResult,
+        ) -> DispatchResultWithPostInfo {
+            let result = with_transaction(|| {
+                for (index, item) in items.into_iter().enumerate() {
+                    if let Err(error) = f(item) {
+                        return TransactionOutcome::Rollback(Err((index as u32, error)));
+                    }
+                }
+                TransactionOutcome::Commit(Ok(()))
+            });
+
+            if let Err((index, error)) = result {
+                // Deposited after the rollback so that the failing item can be found.
+                Self::deposit_event(Event::BatchInterrupted(index, error));
+                return Err(Error::<T>::BatchItemFailed.into());
+            }
+
+            Ok(().into())
+        }
+
+        /// Revoke `proof` on behalf of `sender`.
+        fn do_revoke(sender: T::AccountId, proof: Vec<u8>) -> DispatchResult {
+            // Verify that the specified proof has been claimed.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // Disputed and frozen claims stay put until they are resolved or thawed.
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+
+            if let Some(info) = CoSignedClaims::<T>::get(&proof) {
+                // Co-signed claims are only revoked once enough co-owners asked for it.
+                if !Self::approve_action(&sender, &proof, &info, ClaimAction::Revoke)? {
+                    return Ok(());
+                }
+            } else {
+                // Verify that sender of the current call is the claim owner.
+                ensure!(sender == claim.owner, Error::<T>::NotProofOwner);
+            }
+
+            // Remove claim from storage and refund the deposit recorded at creation time.
+            Self::remove_claim(&proof, &claim);
+
+            // Emit an event that the claim was erased.
+            Self::deposit_event(Event::ClaimRevoked(sender, proof));
+
+
+            Ok(())
+        }
+
+        /// Transfer `proof` to `dist` on behalf of `sender`.
+        fn do_transfer_claim(sender: T::AccountId, proof: Vec<u8>, dist: T::AccountId) -> DispatchResult {
+            // Verify that the specified proof has been claimed.
+            let claim = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+            // Disputed and frozen claims stay put until they are resolved or thawed.
+            ensure!(!Disputes::<T>::contains_key(&proof), Error::<T>::ClaimDisputed);
+            ensure!(!FrozenClaims::<T>::get(&proof), Error::<T>::ClaimIsFrozen);
+
+            if let Some(info) = CoSignedClaims::<T>::get(&proof) {
+                ensure!(info.status == ClaimStatus::Finalized, Error::<T>::ClaimNotFinalized);
+                // Checked before any co-owner approval is recorded.
+                ensure!(dist != claim.owner, Error::<T>::TransferToSelf);
+                // The recipient has to accept claims held by the depositor.
+                ensure!(
+                    TransferApprovals::<T>::get(&dist, &claim.owner),
+                    Error::<T>::TransferNotApproved
+                );
+                if !Self::approve_action(&sender, &proof, &info, ClaimAction::Transfer(dist.clone()))? {
+                    return Ok(());
+                }
+            } else {
+                ensure!(claim.owner == sender, Error::<T>::NotProofOwner);
+                ensure!(dist != sender, Error::<T>::TransferToSelf);
+                ensure!(
+                    TransferApprovals::<T>::get(&dist, &sender),
+                    Error::<T>::TransferNotApproved
+                );
+            }
+
+            Self::do_transfer(claim, dist, proof)
+        }
+
+        /// Hand `claim` over to `dist` together with its deposit, recording the new owner.
+        fn do_transfer(
+            claim: ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+            dist: T::AccountId,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = claim.owner;
+            // A transfer to the owner would only pad the chain of custody.
+            ensure!(dist != sender, Error::<T>::TransferToSelf);
+
+            let mut dist_owned = ClaimsByOwner::<T>::get(&dist);
+            ensure!(
+                dist_owned.len() < T::MaxClaimsPerAccount::get() as usize,
+                Error::<T>::TooManyClaims
+            );
+
+            // A deposit paid through `create_claim_for` stays with its payer, any other moves into
+            // the reserved balance of the new owner.
+            let deposit = if ClaimDepositors::<T>::contains_key(&proof) {
+                claim.deposit
+            } else {
+                let remaining = T::Currency::repatriate_reserved(
+                    &sender,
+                    &dist,
+                    claim.deposit,
+                    BalanceStatus::Reserved,
+                )?;
+                claim.deposit.saturating_sub(remaining)
+            };
+
+            let current_block = <frame_system::Module<T>>::block_number();
+
+            Proofs::<T>::insert(&proof, ClaimInfo {
+                owner: dist.clone(),
+                block_number: current_block,
+                moment: Self::now(),
+                created_at: claim.created_at,
+                deposit,
+                expiry: claim.expiry,
+            });
+
+            Self::record_owner(&proof, &dist, current_block);
+
+            Self::remove_from_owner(&sender, &proof);
+            dist_owned.push(proof.clone());
+            ClaimsByOwner::<T>::insert(&dist, dist_owned);
+
+            // Any outstanding offer was made by the previous owner.
+            PendingTransfers::<T>::remove(&proof);
+
+            // The recipient becomes the sole owner of a co-signed claim.
+            CoSignedClaims::<T>::remove(&proof);
+            PendingActions::<T>::remove(&proof);
+
+            Self::deposit_event(Event::ClaimTransferred(sender, dist, proof));
+
+            Ok(())
+        }
+
+        /// Whether `proof` is claimed and attested by at least `AttestationThreshold` notaries
+        /// which are still registered.
+        pub fn is_verified(proof: &[u8]) -> bool {
+            Proofs::<T>::contains_key(proof) &&
+                Attestations::<T>::get(proof).iter().filter(|notary| Notaries::<T>::get(notary)).count()
+                    >= T::AttestationThreshold::get() as usize
+        }
+
+        /// The commitment to `proof` under `salt` for `owner`: the hash of the encoded
+        /// `(proof, salt, owner)`, whose length prefixes keep the boundary between proof and salt.
+        pub fn commitment_of(proof: &[u8], salt: &[u8], owner: &T::AccountId) -> T::Hash {
+            T::Hashing::hash_of(&(proof, salt, owner))
+        }
+
+        /// The payload an owner signs to authorize `create_claim_for`.
+        ///
+        /// It starts with `DELEGATED_CLAIM_CONTEXT` and the genesis hash, so that the signature is
+        /// valid neither for other calls nor on other chains.
+        pub fn delegated_claim_payload(
+            proof: &[u8],
+            expiry: Option<T::BlockNumber>,
+            nonce: u64,
+            deadline: T::BlockNumber,
+        ) -> Vec<u8> {
+            let genesis_hash = <frame_system::Module<T>>::block_hash(T::BlockNumber::zero());
+            (DELEGATED_CLAIM_CONTEXT, genesis_hash, proof, expiry, nonce, deadline).encode()
+        }
+
+        /// Check that `owner` can claim `proof`.
+        fn ensure_claimable(owner: &T::AccountId, proof: &[u8]) -> DispatchResult {
+            // Limited in length by `StringLimit`
+            ensure!(proof.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+
+            // Verify that the specified proof has not already been claimed.
+            ensure!(!Proofs::<T>::contains_key(proof), Error::<T>::ProofAlreadyClaimed);
+
+            // Verify that the owner can own another claim.
+            ensure!(
+                ClaimsByOwner::<T>::decode_len(owner).unwrap_or(0) < T::MaxClaimsPerAccount::get() as usize,
+                Error::<T>::TooManyClaims
+            );
+
+            Ok(())
+        }
+
+        /// Record `proof` as claimed by `owner` at `block_number` and `moment`, reserving the deposit.
+        fn insert_claim(
+            owner: &T::AccountId,
+            proof: Vec<u8>,
+            block_number: T::BlockNumber,
+            moment: u64,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            Self::insert_claim_paid_by(owner, owner, proof, block_number, moment, expiry)
+        }
+
+        /// Record `proof` as claimed by `owner`, reserving the deposit from `payer`.
+        fn insert_claim_paid_by(
+            owner: &T::AccountId,
+            payer: &T::AccountId,
+            proof: Vec<u8>,
+            block_number: T::BlockNumber,
+            moment: u64,
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            Self::ensure_claimable(owner, &proof)?;
+            if let Some(expiry) = expiry {
+                Self::ensure_expiry_slot(expiry)?;
+            }
+
+            let mut owned = ClaimsByOwner::<T>::get(owner);
+
+            // Reserve the storage deposit from the payer.
+            let deposit = Self::claim_deposit(&proof);
+            T::Currency::reserve(payer, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+            if payer != owner {
+                ClaimDepositors::<T>::insert(&proof, payer);
+            }
+
+            // Store the proof with the owner, block number, deposit and expiry.
+            Proofs::<T>::insert(&proof, ClaimInfo {
+                owner: owner.clone(),
+                block_number,
+                moment,
+                created_at: moment,
+                deposit,
+                expiry,
+            });
+
+            // Schedule the claim for pruning.
+            if let Some(expiry) = expiry {
+                ClaimExpiries::<T>::append(expiry, &proof);
+            }
+
+            // Start the chain of custody with the creator.
+            ClaimHistory::<T>::insert(&proof, vec![(owner.clone(), block_number)]);
+
+            // Index the proof under its owner.
+            owned.push(proof.clone());
+            ClaimsByOwner::<T>::insert(owner, owned);
+
+            // Emit an event that the claim was created.
+            Self::deposit_event(Event::ClaimCreated(owner.clone(), proof, moment));
+
+            Ok(())
+        }
+
+        /// Record the approval of `action` by the co-owner `who`, returning whether the threshold
+        /// has been reached.
+        ///
+        /// A different action is refused while other co-owners approve the pending one, until it is
+        /// carried out or the claim is forcibly revoked or transferred.
+        fn approve_action(
+            who: &T::AccountId,
+            proof: &[u8],
+            info: &CoSignInfo<T::AccountId>,
+            action: ClaimAction<T::AccountId, T::BlockNumber>,
+        ) -> Result<bool, DispatchError> {
+            ensure!(info.co_owners.contains(who), Error::<T>::NotCoOwner);
+
+            let mut approvals = match PendingActions::<T>::get(proof) {
+                Some((pending, approvals)) if pending == action => approvals,
+                // Only the co-owner who alone approved the pending action may replace it.
+                Some((_, approvals)) => {
+                    ensure!(approvals.iter().all(|approver| approver == who), Error::<T>::ActionPending);
+                    Vec::new()
+                }
+                None => Vec::new(),
+            };
+            ensure!(!approvals.contains(who), Error::<T>::AlreadyApproved);
+            approvals.push(who.clone());
+
+            // The caller carries out the action, which drops the pending entry with the claim.
+            if approvals.len() as u32 >= info.threshold {
+                return Ok(true);
+            }
+
+            PendingActions::<T>::insert(proof, (action.clone(), approvals));
+            Self::deposit_event(Event::ClaimActionApproved(who.clone(), proof.to_vec(), action));
+            Ok(false)
+        }
+
+        /// Claim `digest` tagged with `algorithm` for `owner` at the current block and record the
+        /// algorithm used.
+        fn insert_hashed_claim(
+            owner: &T::AccountId,
+            algorithm: HashAlgorithm,
+            digest: [u8; 32],
+            expiry: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            Self::ensure_valid_expiry(expiry)?;
+            let current_block = <frame_system::Module<T>>::block_number();
+            let proof = algorithm.tagged(&digest);
+            Self::insert_claim(owner, proof.clone(), current_block, Self::now(), expiry)?;
+            ProofAlgorithms::<T>::insert(proof, algorithm);
+            Ok(())
+        }
+
+        fn ensure_valid_metadata(metadata: &ClaimMetadata) -> DispatchResult {
+            ensure!(
+                metadata.title.len() <= T::MaxTitleLength::get() as usize
+                    && metadata.mime_type.len() <= T::MaxMimeTypeLength::get() as usize
+                    && metadata.uri.len() <= T::MaxUriLength::get() as usize
+                    && metadata.tags.len() <= T::MaxTags::get() as usize
+                    && metadata.tags.iter().all(|tag| tag.len() <= T::MaxTagLength::get() as usize),
+                Error::<T>::BadMetadata
+            );
+            Ok(())
+        }
+
+        fn ensure_valid_expiry(expiry: Option<T::BlockNumber>) -> DispatchResult {
+            if let Some(expiry) = expiry {
+                let now = <frame_system::Module<T>>::block_number();
+                ensure!(
+                    expiry > now && expiry - now <= T::MaxClaimDuration::get(),
+                    Error::<T>::InvalidExpiry
+                );
+            }
+            Ok(())
+        }
+
+        /// Remove `proof` with all its bookkeeping and refund the deposit to the owner.
+        fn remove_claim(
+            proof: &[u8],
+            claim: &ClaimInfo<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        ) {
+            let holder = Self::deposit_holder(proof, &claim.owner);
+            Proofs::<T>::remove(proof);
+            ClaimHistory::<T>::remove(proof);
+            ClaimDepositors::<T>::remove(proof);
+            ProofAlgorithms::<T>::remove(proof);
+            PendingTransfers::<T>::remove(proof);
+            CoSignedClaims::<T>::remove(proof);
+            PendingActions::<T>::remove(proof);
+            ClaimMetadataOf::<T>::remove(proof);
+            Attestations::<T>::remove(proof);
+            FrozenClaims::<T>::remove(proof);
+            // An open dispute is dropped with the claim and the bond of its challenger refunded.
+            if let Some(dispute) = Disputes::<T>::take(proof) {
+                T::Currency::unreserve(&dispute.challenger, dispute.bond);
+            }
+            Self::remove_from_owner(&claim.owner, proof);
+            if let Some(expiry) = claim.expiry {
+                Self::remove_from_expiries(expiry, proof);
+            }
+            T::Currency::unreserve(&holder, claim.deposit);
+        }
+
+        /// The deposit reserved for storing `metadata`, by its encoded length.
+        pub fn metadata_deposit(metadata: &ClaimMetadata) -> BalanceOf<T> {
+            let bytes: BalanceOf<T> = (metadata.encoded_size() as u32).into();
+            T::ClaimDepositPerByte::get().saturating_mul(bytes)
+        }
+
+        /// Replace the metadata of `proof`, reserving the deposit of the new metadata from the owner
+        /// and refunding that of the old one.
+        ///
+        /// A deposit paid by a relayer is taken over by the owner as a whole.
+        fn update_metadata(proof: &[u8], metadata: Option<ClaimMetadata>) -> DispatchResult {
+            let mut claim = Proofs::<T>::get(proof).ok_or(Error::<T>::NoSuchProof)?;
+            let old = ClaimMetadataOf::<T>::get(proof)
+                .map_or_else(Zero::zero, |metadata| Self::metadata_deposit(&metadata));
+            let new = metadata.as_ref().map_or_else(Zero::zero, Self::metadata_deposit);
+            let deposit = claim.deposit.saturating_sub(old).saturating_add(new);
+
+            match ClaimDepositors::<T>::get(proof) {
+                Some(payer) => {
+                    T::Currency::reserve(&claim.owner, deposit)
+                        .map_err(|_| Error::<T>::InsufficientBalance)?;
+                    T::Currency::unreserve(&payer, claim.deposit);
+                    ClaimDepositors::<T>::remove(proof);
+                }
+                None if deposit > claim.deposit => {
+                    T::Currency::reserve(&claim.owner, deposit - claim.deposit)
+                        .map_err(|_| Error::<T>::InsufficientBalance)?;
+                }
+                None => {
+                    T::Currency::unreserve(&claim.owner, claim.deposit - deposit);
+                }
+            }
+            claim.deposit = deposit;
+            Proofs::<T>::insert(proof, claim);
+
+            match metadata {
+                Some(metadata) => ClaimMetadataOf::<T>::insert(proof, metadata),
+                None => ClaimMetadataOf::<T>::remove(proof),
+            }
+            Ok(())
+        }
+
+        /// The account holding the deposit of `proof`: the account which paid it if any, otherwise
+        /// the owner.
+        fn deposit_holder(proof: &[u8], owner: &T::AccountId) -> T::AccountId {
+            ClaimDepositors::<T>::get(proof).unwrap_or_else(|| owner.clone())
+        }
+
+        /// Schedule `proof` for pruning at the first following block with room left if it expired
+        /// while it could not be pruned.
+        fn reschedule_if_expired(proof: &[u8], expiry: Option<T::BlockNumber>) {
+            let current_block = <frame_system::Module<T>>::block_number();
+            if let Some(expiry) = expiry {
+                if expiry <= current_block {
+                    let mut at = current_block + One::one();
+                    while Self::ensure_expiry_slot(at).is_err() {
+                        at += One::one();
+                    }
+                    ClaimExpiries::<T>::append(at, proof);
+                }
+            }
+        }
+
+        /// Ensure that fewer than `MaxExpiriesPerBlock` claims expire at `expiry`.
+        fn ensure_expiry_slot(expiry: T::BlockNumber) -> DispatchResult {
+            ensure!(
+                ClaimExpiries::<T>::decode_len(expiry).unwrap_or(0) < T::MaxExpiriesPerBlock::get() as usize,
+                Error::<T>::ExpiryBlockFull
+            );
+            Ok(())
+        }
+
+        fn remove_from_expiries(expiry: T::BlockNumber, proof: &[u8]) {
+            ClaimExpiries::<T>::mutate_exists(expiry, |maybe_due| {
+                if let Some(due) = maybe_due {
+                    due.retain(|p| p.as_slice() != proof);
+                    if due.is_empty() {
+                        *maybe_due = None;
+                    }
+                }
+            });
+        }
+
+        /// Append `owner` to the chain of custody of `proof`. Beyond `MaxHistoryLength` entries the
+        /// creator is kept and the oldest later owners are dropped.
+        fn record_owner(proof: &[u8], owner: &T::AccountId, block_number: T::BlockNumber) {
+            let mut history = ClaimHistory::<T>::get(proof);
+            history.push((owner.clone(), block_number));
+            let excess = history.len().saturating_sub(T::MaxHistoryLength::get() as usize);
+            history.drain(1 .. 1 + excess);
+            ClaimHistory::<T>::insert(proof, history);
+        }
+
+        fn remove_from_owner(owner: &T::AccountId, proof: &[u8]) {
+            ClaimsByOwner::<T>::mutate_exists(owner, |maybe_owned| {
+                if let Some(owned) = maybe_owned {
+                    owned.retain(|p| p.as_slice() != proof);
+                    if owned.is_empty() {
+                        *maybe_owned = None;
+                    }
+                }
+            });
+        }
     }
-}
\ No newline at end of file
+}
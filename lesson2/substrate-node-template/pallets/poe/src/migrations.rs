@@ -0,0 +1,109 @@
+//! Storage migrations of the poe pallet.
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
+use codec::Decode;
+use frame_support::storage::{unhashed, StoragePrefixedMap};
+use sp_std::vec::Vec;
+
+use crate::{
+    ClaimHistory, ClaimInfo, ClaimsByOwner, Config, Pallet, Proofs, Releases, StorageVersion,
+};
+
+/// The layout of a claim up to [`Releases::V1_0_0`]: its owner and the block it was made at.
+pub type ClaimV1<AccountId, BlockNumber> = (AccountId, BlockNumber);
+
+/// Where [`pre_migrate_to_v2`] leaves the number of legacy claims for [`post_migrate_to_v2`].
+const LEGACY_CLAIM_COUNT: &[u8] = b":poe:migration:legacy_claim_count";
+
+/// Translate every claim stored before [`Releases::V2_0_0`] into a [`ClaimInfo`].
+///
+/// Legacy claims paid no deposit and never expire. Their moment is estimated from the number of
+/// blocks since the claim was made, assuming `ExpectedBlockTime` between blocks, and they are
+/// indexed under their owner with the owner as the only entry of their chain of custody.
+///
+/// A legacy transfer overwrote the block of the claim, so `created_at` is estimated from the same
+/// block as `moment`.
+pub fn migrate_to_v2<T: Config>() -> Weight {
+    if StorageVersion::<T>::get() != Releases::V1_0_0 {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let current_block = <frame_system::Module<T>>::block_number();
+    let now = Pallet::<T>::now();
+    let moment_at = |block_number: T::BlockNumber| -> u64 {
+        let elapsed: u64 = current_block.saturating_sub(block_number).saturated_into();
+        now.saturating_sub(elapsed.saturating_mul(T::ExpectedBlockTime::get()))
+    };
+
+    let mut translated: Weight = 0;
+
+    Proofs::<T>::translate::<ClaimV1<T::AccountId, T::BlockNumber>, _>(|proof, (owner, block_number)| {
+        translated += 1;
+        ClaimsByOwner::<T>::append(&owner, &proof);
+        ClaimHistory::<T>::append(&proof, (owner.clone(), block_number));
+        let moment = moment_at(block_number);
+        Some(ClaimInfo {
+            owner,
+            block_number,
+            moment,
+            created_at: moment,
+            deposit: Zero::zero(),
+            expiry: None,
+        })
+    });
+
+    StorageVersion::<T>::put(Releases::V2_0_0);
+
+    T::DbWeight::get().reads_writes(translated + 1, translated * 3 + 1)
+}
+
+/// Check that every claim decodes in the legacy layout and remember how many there are.
+pub fn pre_migrate_to_v2<T: Config>() -> Result<(), &'static str> {
+    if StorageVersion::<T>::get() != Releases::V1_0_0 {
+        return Ok(());
+    }
+
+    let prefix = Proofs::<T>::final_prefix();
+    let mut key: Vec<u8> = prefix.to_vec();
+    let mut count: u32 = 0;
+    while let Some(next) = sp_io::storage::next_key(&key) {
+        if !next.starts_with(&prefix) {
+            break;
+        }
+        let raw = unhashed::get_raw(&next).ok_or("claim vanished while iterating")?;
+        <ClaimV1<T::AccountId, T::BlockNumber>>::decode(&mut &raw[..])
+            .map_err(|_| "claim is not in the V1_0_0 layout")?;
+        count += 1;
+        key = next;
+    }
+
+    unhashed::put(LEGACY_CLAIM_COUNT, &count);
+    Ok(())
+}
+
+/// Check that the layout was bumped and that every legacy claim survived the translation.
+pub fn post_migrate_to_v2<T: Config>() -> Result<(), &'static str> {
+    if StorageVersion::<T>::get() != Releases::V2_0_0 {
+        return Err("storage version was not bumped to V2_0_0");
+    }
+
+    let expected = match unhashed::take::<u32>(LEGACY_CLAIM_COUNT) {
+        Some(expected) => expected,
+        // The migration had already run.
+        None => return Ok(()),
+    };
+
+    let mut count: u32 = 0;
+    for (proof, claim) in Proofs::<T>::iter() {
+        if !ClaimsByOwner::<T>::get(&claim.owner).contains(&proof) {
+            return Err("migrated claim is not indexed under its owner");
+        }
+        count += 1;
+    }
+    if count != expected {
+        return Err("claims were lost by the migration");
+    }
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use crate::{Error, mock::*};
-use frame_support::{assert_ok, assert_noop};
+use codec::Encode;
+use frame_support::{assert_err, assert_ok, assert_noop};
+use sp_core::H256;
+use sp_runtime::{testing::TestSignature, traits::{BlakeTwo256, Hash}};
 use super::*;
 
 #[test]
@@ -8,25 +11,45 @@ fn claim_should_work() {
         let claim = vec![0,1,2];
         let limit = StringLimit::get() as usize;
 
-        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
-        assert_eq!(Proofs::<Test>::get(&claim), (1, frame_system::Pallet::<Test>::block_number()));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap(), ClaimInfo {
+            owner: 1,
+            block_number: frame_system::Pallet::<Test>::block_number(),
+            moment: 6000,
+            created_at: 6000,
+            deposit: 13,
+            expiry: None,
+        });
+        // base deposit plus one unit per byte is reserved
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(Balances::free_balance(1), 87);
         // proof string limit length check
         assert_noop!(
-            PoeModule::create_claim(Origin::signed(1), vec![0u8; limit + 1]),
+            PoeModule::create_claim(Origin::signed(1), vec![0u8; limit + 1], None, None),
             Error::<Test>::BadMetadata
         );
     })
 }
 
 
+#[test]
+fn claim_failed_when_deposit_not_affordable() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(3), vec![0,1,2], None, None),
+            Error::<Test>::InsufficientBalance
+        );
+    })
+}
+
 #[test]
 fn claim_failed_when_claim_exist() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
 
-        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
         assert_noop!(
-            PoeModule::create_claim(Origin::signed(2), claim.clone()),
+            PoeModule::create_claim(Origin::signed(2), claim.clone(), None, None),
             Error::<Test>::ProofAlreadyClaimed
         );
     })
@@ -36,14 +59,30 @@ fn claim_failed_when_claim_exist() {
 fn revoke_should_work() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
 
         assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
         assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
     })
 }
 
 
+#[test]
+fn revoke_refunds_recorded_deposit() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+
+        // a deposit parameter change must not affect the refund of existing claims
+        ClaimDepositBase::set(50);
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+    })
+}
+
 #[test]
 fn revoke_failed_when_claim_not_exist() {
     new_test_ext().execute_with(|| {
@@ -57,7 +96,7 @@ fn revoke_failed_when_claim_not_exist() {
 fn revoke_failed_when_not_owner() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
 
         assert_noop!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()), Error::<Test>::NotProofOwner);
     })
@@ -67,10 +106,70 @@ fn revoke_failed_when_not_owner() {
 fn transfer_should_work() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        // the transfer is dated anew but the claim keeps its creation time
+        Timestamp::set_timestamp(12_000);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap(), ClaimInfo {
+            owner: 2,
+            block_number: frame_system::Pallet::<Test>::block_number(),
+            moment: 12_000,
+            created_at: 6000,
+            deposit: 13,
+            expiry: None,
+        });
+        // the deposit follows the claim to the new owner
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(2), 13);
+        assert_eq!(Balances::free_balance(2), 100);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimTransferred(1, 2, claim.clone()))
+        );
+    })
+}
+
+#[test]
+fn transfer_records_history() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        System::set_block_number(5);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (2, 5)]);
+        // the original claim block stays in the history
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().block_number, 5);
+
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(ClaimHistory::<Test>::contains_key(&claim), false);
+    })
+}
+
+#[test]
+fn transfer_should_drop_oldest_history() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+        // the history holds 3 entries
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(1), 2));
+        System::set_block_number(2);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        System::set_block_number(3);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 1));
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (2, 2), (1, 3)]);
 
+        // a full history keeps its creator and drops the oldest later entry, the creation time stays on
+        // the claim
+        System::set_block_number(4);
         assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
-        assert_eq!(Proofs::<Test>::get(&claim), (2, frame_system::Pallet::<Test>::block_number()));
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (1, 3), (2, 4)]);
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().created_at, 6000);
     })
 }
 
@@ -87,8 +186,1498 @@ fn transfer_failed_when_claim_not_exist() {
 fn transfer_failed_when_not_owner() {
     new_test_ext().execute_with(|| {
         let claim = vec![0,1,2];
-        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone());
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
 
         assert_noop!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 1), Error::<Test>::NotProofOwner);
     })
-}
\ No newline at end of file
+}
+
+#[test]
+fn transfer_failed_when_to_self() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let _ = PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None);
+        assert_ok!(PoeModule::approve_sender(Origin::signed(1), 1));
+
+        // neither a direct transfer nor an offer may target the owner
+        assert_noop!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 1), Error::<Test>::TransferToSelf);
+        assert_noop!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 1), Error::<Test>::TransferToSelf);
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1)]);
+    })
+}
+
+#[test]
+fn claims_by_owner_should_follow_claims() {
+    new_test_ext().execute_with(|| {
+        let claim_1 = vec![0,1,2];
+        let claim_2 = vec![3,4];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_1.clone(), None, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_2.clone(), None, None));
+        assert_eq!(PoeModule::claims_by_owner(1), vec![claim_1.clone(), claim_2.clone()]);
+
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim_1.clone(), 2));
+        assert_eq!(PoeModule::claims_by_owner(1), vec![claim_2.clone()]);
+        assert_eq!(PoeModule::claims_by_owner(2), vec![claim_1.clone()]);
+
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim_2.clone()));
+        assert_eq!(ClaimsByOwner::<Test>::contains_key(1), false);
+    })
+}
+
+#[test]
+fn claims_of_should_paginate() {
+    new_test_ext().execute_with(|| {
+        let owned: Vec<Vec<u8>> = (0..25u8).map(|i| vec![i]).collect();
+        ClaimsByOwner::<Test>::insert(1, owned.clone());
+
+        assert_eq!(PoeModule::claims_of(&1, 0), owned[..20].to_vec());
+        assert_eq!(PoeModule::claims_of(&1, 1), owned[20..].to_vec());
+        assert_eq!(PoeModule::claims_of(&1, 2), Vec::<Vec<u8>>::new());
+    })
+}
+
+#[test]
+fn claim_failed_when_too_many_claims() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![0], None, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![1], None, None));
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(1), vec![2], None, None),
+            Error::<Test>::TooManyClaims
+        );
+
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![3], None, None));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(1), 2));
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(2), vec![3], 1),
+            Error::<Test>::TooManyClaims
+        );
+    })
+}
+
+#[test]
+fn claim_failed_when_expiry_invalid() {
+    new_test_ext().execute_with(|| {
+        // expiry must be in the future
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(1), vec![0], Some(1), None),
+            Error::<Test>::InvalidExpiry
+        );
+        // and not further away than `MaxClaimDuration`
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(1), vec![0], Some(12), None),
+            Error::<Test>::InvalidExpiry
+        );
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![0], Some(11), None));
+    })
+}
+
+#[test]
+fn expired_claims_should_be_pruned() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+        assert_eq!(PoeModule::claim_expiries(3), vec![claim.clone()]);
+
+        run_to_block(2);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+
+        run_to_block(3);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(ClaimExpiries::<Test>::contains_key(3), false);
+        assert_eq!(ClaimsByOwner::<Test>::contains_key(1), false);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimExpired(1, claim.clone()))
+        );
+    })
+}
+
+#[test]
+fn expiry_block_should_be_capped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![0], Some(3), None));
+        // only one claim may expire per block
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(2), vec![1], Some(3), None),
+            Error::<Test>::ExpiryBlockFull
+        );
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![1], Some(4), None));
+
+        // renewing into a full block is refused too, unless it is the current expiry of the claim
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(2), vec![1], Some(3)),
+            Error::<Test>::ExpiryBlockFull
+        );
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), vec![0], Some(3)));
+        assert_eq!(PoeModule::claim_expiries(3), vec![vec![0]]);
+
+        // a claim rescheduled after a dispute skips the full blocks
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), vec![0], H256::repeat_byte(1)));
+        run_to_block(3);
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), vec![0], true));
+        assert_eq!(PoeModule::claim_expiries(4), vec![vec![1]]);
+        assert_eq!(PoeModule::claim_expiries(5), vec![vec![0]]);
+
+        run_to_block(5);
+        assert_eq!(Proofs::<Test>::contains_key(vec![0]), false);
+        assert_eq!(Proofs::<Test>::contains_key(vec![1]), false);
+    })
+}
+
+#[test]
+fn renew_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(5)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().expiry, Some(5));
+        assert_eq!(ClaimExpiries::<Test>::contains_key(3), false);
+        assert_eq!(PoeModule::claim_expiries(5), vec![claim.clone()]);
+
+        run_to_block(4);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+
+        // a permanent claim is never pruned
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), claim.clone(), None));
+        run_to_block(6);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+    })
+}
+
+#[test]
+fn renew_failed_when_not_owner() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(2), claim.clone(), Some(5)),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(1), vec![9], Some(5)),
+            Error::<Test>::NoSuchProof
+        );
+    })
+}
+
+#[test]
+fn renew_failed_when_claim_locked() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+
+        // an owner cannot shorten a disputed claim to escape the challenge
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(2)),
+            Error::<Test>::ClaimDisputed
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), None),
+            Error::<Test>::ClaimDisputed
+        );
+    })
+}
+
+fn leaf(document: H256) -> H256 {
+    BlakeTwo256::hash_of(&(MERKLE_LEAF_PREFIX, document))
+}
+
+fn hash_pair(a: H256, b: H256) -> H256 {
+    if a <= b {
+        BlakeTwo256::hash_of(&(MERKLE_NODE_PREFIX, a, b))
+    } else {
+        BlakeTwo256::hash_of(&(MERKLE_NODE_PREFIX, b, a))
+    }
+}
+
+// A three document batch: root = node(node(leaf(a), leaf(b)), leaf(c))
+fn batch() -> (H256, [H256; 3]) {
+    let leaves = [
+        BlakeTwo256::hash(&[0]),
+        BlakeTwo256::hash(&[1]),
+        BlakeTwo256::hash(&[2]),
+    ];
+    let root = hash_pair(hash_pair(leaf(leaves[0]), leaf(leaves[1])), leaf(leaves[2]));
+    (root, leaves)
+}
+
+#[test]
+fn anchor_batch_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, _) = batch();
+
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+        assert_eq!(PoeModule::batches(root), Some(BatchInfo {
+            owner: 1,
+            block_number: 1,
+            moment: 6000,
+            created_at: 6000,
+            leaf_count: 3,
+            deposit: 42,
+        }));
+        assert_eq!(Balances::reserved_balance(1), 42);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::BatchAnchored(1, root, 3))
+        );
+
+        assert_noop!(
+            PoeModule::anchor_batch(Origin::signed(2), root, 3),
+            Error::<Test>::BatchAlreadyAnchored
+        );
+        assert_noop!(
+            PoeModule::anchor_batch(Origin::signed(2), H256::repeat_byte(1), 0),
+            Error::<Test>::EmptyBatch
+        );
+    })
+}
+
+#[test]
+fn revoke_batch_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, _) = batch();
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+
+        assert_noop!(
+            PoeModule::revoke_batch(Origin::signed(2), root),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::revoke_batch(Origin::signed(1), root));
+        assert_eq!(PoeModule::batches(root), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_noop!(
+            PoeModule::revoke_batch(Origin::signed(1), root),
+            Error::<Test>::NoSuchBatch
+        );
+    })
+}
+
+#[test]
+fn verify_inclusion_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, [a, b, c]) = batch();
+
+        // nothing is included in a batch which was never anchored
+        assert_eq!(PoeModule::verify_inclusion(root, c, &[hash_pair(leaf(a), leaf(b))]), false);
+
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+        assert_eq!(PoeModule::verify_inclusion(root, a, &[leaf(b), leaf(c)]), true);
+        assert_eq!(PoeModule::verify_inclusion(root, b, &[leaf(a), leaf(c)]), true);
+        assert_eq!(PoeModule::verify_inclusion(root, c, &[hash_pair(leaf(a), leaf(b))]), true);
+
+        assert_eq!(PoeModule::verify_inclusion(root, a, &[leaf(c), leaf(b)]), false);
+        assert_eq!(PoeModule::verify_inclusion(root, H256::repeat_byte(1), &[leaf(b), leaf(c)]), false);
+        // proofs deeper than the batch are rejected
+        assert_eq!(PoeModule::verify_inclusion(root, a, &[leaf(b), leaf(c), leaf(c)]), false);
+        // an inner node cannot pass as a document
+        assert_eq!(PoeModule::verify_inclusion(root, hash_pair(leaf(a), leaf(b)), &[leaf(c)]), false);
+    })
+}
+
+#[test]
+fn claim_from_batch_should_work() {
+    new_test_ext().execute_with(|| {
+        let (root, [a, b, c]) = batch();
+        assert_ok!(PoeModule::anchor_batch(Origin::signed(1), root, 3));
+        run_to_block(5);
+
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(2), root, a, vec![leaf(b), leaf(c)]),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(1), root, a, vec![leaf(c), leaf(b)]),
+            Error::<Test>::InvalidMerkleProof
+        );
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(1), root, hash_pair(leaf(a), leaf(b)), vec![leaf(c)]),
+            Error::<Test>::InvalidMerkleProof
+        );
+
+        assert_ok!(PoeModule::claim_from_batch(Origin::signed(1), root, a, vec![leaf(b), leaf(c)]));
+        // the claim keeps the priority date of the batch
+        assert_eq!(Proofs::<Test>::get(a.as_ref().to_vec()).unwrap(), ClaimInfo {
+            owner: 1,
+            block_number: 1,
+            moment: 6000,
+            created_at: 6000,
+            deposit: 42,
+            expiry: None,
+        });
+        assert_eq!(Balances::reserved_balance(1), 84);
+        assert_eq!(PoeModule::claims_by_owner(1), vec![a.as_ref().to_vec()]);
+
+        assert_noop!(
+            PoeModule::claim_from_batch(Origin::signed(1), root, a, vec![leaf(b), leaf(c)]),
+            Error::<Test>::ProofAlreadyClaimed
+        );
+    })
+}
+
+#[test]
+fn hash_and_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        // content longer than StringLimit but within MaxContentLength
+        let content = vec![7u8; 40];
+        let digest = sp_io::hashing::sha2_256(&content);
+        let proof = HashAlgorithm::Sha2_256.tagged(&digest);
+
+        assert_ok!(PoeModule::hash_and_claim(
+            Origin::signed(1), HashAlgorithm::Sha2_256, content.clone(), None
+        ));
+        assert_eq!(Proofs::<Test>::get(&proof).unwrap().owner, 1);
+        assert_eq!(PoeModule::proof_algorithm(&proof), Some(HashAlgorithm::Sha2_256));
+        // the key carries a one-byte algorithm tag
+        assert_eq!(Balances::reserved_balance(1), 43);
+
+        // the same digest cannot be claimed again under the same algorithm
+        assert_noop!(
+            PoeModule::create_hashed_claim(Origin::signed(2), HashAlgorithm::Sha2_256, digest, None),
+            Error::<Test>::ProofAlreadyClaimed
+        );
+        assert_noop!(
+            PoeModule::hash_and_claim(Origin::signed(1), HashAlgorithm::Sha2_256, vec![0u8; 65], None),
+            Error::<Test>::ContentTooLong
+        );
+
+        // revoking the claim forgets the algorithm
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), proof.clone()));
+        assert_eq!(PoeModule::proof_algorithm(&proof), None);
+    })
+}
+
+#[test]
+fn create_hashed_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        let digest = sp_io::hashing::keccak_256(b"document");
+        let proof = HashAlgorithm::Keccak256.tagged(&digest);
+
+        assert_ok!(PoeModule::create_hashed_claim(
+            Origin::signed(1), HashAlgorithm::Keccak256, digest, Some(3)
+        ));
+        assert_eq!(PoeModule::proof_algorithm(&proof), Some(HashAlgorithm::Keccak256));
+        assert_eq!(PoeModule::claim_expiries(3), vec![proof.clone()]);
+
+        // the algorithm follows the claim when it is transferred
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), proof.clone(), 2));
+        assert_eq!(PoeModule::proof_algorithm(&proof), Some(HashAlgorithm::Keccak256));
+
+        // plain claims have no algorithm
+        assert_eq!(PoeModule::proof_algorithm(vec![0, 1, 2]), None);
+    })
+}
+
+#[test]
+fn hashed_claims_should_be_separated_by_algorithm() {
+    new_test_ext().execute_with(|| {
+        let digest = sp_io::hashing::keccak_256(b"document");
+
+        // equal digests are claimed independently per algorithm and as a plain claim
+        assert_ok!(PoeModule::create_hashed_claim(Origin::signed(1), HashAlgorithm::Keccak256, digest, None));
+        assert_ok!(PoeModule::create_hashed_claim(Origin::signed(2), HashAlgorithm::Sha2_256, digest, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), digest.to_vec(), None, None));
+
+        assert_eq!(Proofs::<Test>::get(HashAlgorithm::Keccak256.tagged(&digest)).unwrap().owner, 1);
+        assert_eq!(Proofs::<Test>::get(HashAlgorithm::Sha2_256.tagged(&digest)).unwrap().owner, 2);
+        assert_eq!(PoeModule::proof_algorithm(digest.to_vec()), None);
+    })
+}
+
+#[test]
+fn transfer_failed_when_not_approved() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // a claim cannot be pushed onto an account without its consent
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2),
+            Error::<Test>::TransferNotApproved
+        );
+
+        // an approved sender can transfer directly
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_eq!(PoeModule::is_sender_approved(2, 1), true);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), vec![3], None, None));
+
+        // the approval can be withdrawn
+        assert_ok!(PoeModule::unapprove_sender(Origin::signed(2), 1));
+        assert_eq!(PoeModule::is_sender_approved(2, 1), false);
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), vec![3], 2),
+            Error::<Test>::TransferNotApproved
+        );
+    })
+}
+
+#[test]
+fn offer_and_accept_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        assert_noop!(
+            PoeModule::offer_claim(Origin::signed(2), claim.clone(), 3),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::pending_transfer(&claim), Some(2));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::TransferOffered(1, 2, claim.clone()))
+        );
+
+        // only the recipient can accept
+        assert_noop!(
+            PoeModule::accept_claim(Origin::signed(3), claim.clone()),
+            Error::<Test>::NotTransferRecipient
+        );
+
+        // the claim and its deposit move on acceptance
+        assert_ok!(PoeModule::accept_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 2);
+        assert_eq!(Balances::reserved_balance(2), 13);
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimTransferred(1, 2, claim.clone()))
+        );
+        assert_noop!(
+            PoeModule::accept_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::NoPendingTransfer
+        );
+    })
+}
+
+#[test]
+fn cancel_offer_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_noop!(
+            PoeModule::cancel_offer(Origin::signed(1), claim.clone()),
+            Error::<Test>::NoPendingTransfer
+        );
+
+        assert_ok!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2));
+        // only the owner can cancel
+        assert_noop!(
+            PoeModule::cancel_offer(Origin::signed(2), claim.clone()),
+            Error::<Test>::NotProofOwner
+        );
+        assert_ok!(PoeModule::cancel_offer(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::TransferCancelled(1, 2, claim.clone()))
+        );
+        assert_noop!(
+            PoeModule::accept_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::NoPendingTransfer
+        );
+    })
+}
+
+#[test]
+fn offers_should_not_outlive_ownership() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // a direct transfer drops the outstanding offer
+        assert_ok!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 3));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+
+        // revoking a claim drops its offer
+        assert_ok!(PoeModule::offer_claim(Origin::signed(2), claim.clone(), 3));
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(PoeModule::pending_transfer(&claim), None);
+    })
+}
+
+#[test]
+fn cosigned_claim_should_finalize() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 3], 2, None));
+        // the creator counts as the first approval
+        assert_eq!(PoeModule::co_signed_claim(&claim), Some(CoSignInfo {
+            co_owners: vec![1, 2, 3],
+            threshold: 2,
+            approvals: vec![1],
+            status: ClaimStatus::Pending,
+        }));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(1), claim.clone()),
+            Error::<Test>::AlreadyApproved
+        );
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(4), claim.clone()),
+            Error::<Test>::NotCoOwner
+        );
+
+        assert_ok!(PoeModule::approve_claim(Origin::signed(3), claim.clone()));
+        assert_eq!(PoeModule::co_signed_claim(&claim).unwrap().status, ClaimStatus::Finalized);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimFinalized(claim.clone()))
+        );
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::AlreadyFinalized
+        );
+
+        // single-owner claims are final from the start
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![3], None, None));
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(2), vec![3]),
+            Error::<Test>::AlreadyFinalized
+        );
+        assert_noop!(
+            PoeModule::approve_claim(Origin::signed(2), vec![4]),
+            Error::<Test>::NoSuchProof
+        );
+    })
+}
+
+#[test]
+fn cosigned_claim_failed_when_invalid() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        // the creator is always a co-owner
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 3, 4], 2, None),
+            Error::<Test>::TooManyCoOwners
+        );
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 2], 2, None),
+            Error::<Test>::DuplicateCoOwner
+        );
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 0, None),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![1, 2], 3, None),
+            Error::<Test>::InvalidThreshold
+        );
+
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 1, None));
+        assert_eq!(PoeModule::co_signed_claim(&claim).unwrap().status, ClaimStatus::Finalized);
+    })
+}
+
+#[test]
+fn cosigned_transfer_requires_threshold() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2, 3], 2, None));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(3), 1));
+
+        // a pending claim cannot be transferred
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3),
+            Error::<Test>::ClaimNotFinalized
+        );
+        assert_ok!(PoeModule::approve_claim(Origin::signed(2), claim.clone()));
+
+        // the claim stays in place below the threshold
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(PoeModule::pending_action(&claim), Some((ClaimAction::Transfer(3), vec![1])));
+        assert_noop!(
+            PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3),
+            Error::<Test>::AlreadyApproved
+        );
+
+        // another co-owner cannot replace the pending action
+        assert_noop!(
+            PoeModule::revoke_claim(Origin::signed(2), claim.clone()),
+            Error::<Test>::ActionPending
+        );
+        // the co-owner who alone approved it may change their mind
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::pending_action(&claim), Some((ClaimAction::Revoke, vec![1])));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(2), claim.clone(), 3));
+
+        // the recipient becomes the sole owner
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 3);
+        assert_eq!(Balances::reserved_balance(3), 13);
+        assert_eq!(PoeModule::co_signed_claim(&claim), None);
+        assert_eq!(PoeModule::pending_action(&claim), None);
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim.clone()));
+    })
+}
+
+#[test]
+fn cosigned_revoke_requires_threshold() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 2, None));
+
+        // co-signed claims cannot be offered
+        assert_noop!(
+            PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2),
+            Error::<Test>::CoSignedClaim
+        );
+        assert_noop!(
+            PoeModule::revoke_claim(Origin::signed(3), claim.clone()),
+            Error::<Test>::NotCoOwner
+        );
+
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimActionApproved(2, claim.clone(), ClaimAction::Revoke))
+        );
+
+        // the claim is removed and the creator refunded at the threshold
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(PoeModule::co_signed_claim(&claim), None);
+        assert_eq!(PoeModule::pending_action(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn cosigned_renew_requires_threshold() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_cosigned_claim(Origin::signed(1), claim.clone(), vec![2], 2, None));
+
+        // the creator alone cannot make the claim expire
+        assert_ok!(PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(3)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().expiry, None);
+        assert_eq!(PoeModule::pending_action(&claim), Some((ClaimAction::Renew(Some(3)), vec![1])));
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(3), claim.clone(), Some(3)),
+            Error::<Test>::NotCoOwner
+        );
+
+        assert_ok!(PoeModule::renew_claim(Origin::signed(2), claim.clone(), Some(3)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().expiry, Some(3));
+        assert_eq!(PoeModule::claim_expiries(3), vec![claim.clone()]);
+        assert_eq!(PoeModule::pending_action(&claim), None);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimRenewed(1, claim.clone(), Some(3)))
+        );
+    })
+}
+
+fn metadata() -> ClaimMetadata {
+    ClaimMetadata {
+        title: b"contract".to_vec(),
+        mime_type: b"application/pdf".to_vec(),
+        uri: b"ipfs://Qm".to_vec(),
+        tags: vec![b"law".to_vec(), b"2021".to_vec()],
+    }
+}
+
+#[test]
+fn claim_with_metadata_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, Some(metadata())));
+        assert_eq!(PoeModule::claim_metadata(&claim), Some(metadata()));
+        // the deposit covers the encoded metadata: 13 + 45
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().deposit, 58);
+        assert_eq!(Balances::reserved_balance(1), 58);
+
+        // the balance has to cover both deposits
+        Balances::make_free_balance_be(&2, 20);
+        assert_noop!(
+            PoeModule::create_claim(Origin::signed(2), vec![3], None, Some(metadata())),
+            Error::<Test>::InsufficientBalance
+        );
+        Balances::make_free_balance_be(&2, 100);
+
+        // every field has its own limit
+        let invalid = vec![
+            ClaimMetadata { title: vec![0; 9], ..metadata() },
+            ClaimMetadata { mime_type: vec![0; 17], ..metadata() },
+            ClaimMetadata { uri: vec![0; 17], ..metadata() },
+            ClaimMetadata { tags: vec![vec![0]; 3], ..metadata() },
+            ClaimMetadata { tags: vec![vec![0; 5]], ..metadata() },
+        ];
+        for metadata in invalid {
+            assert_noop!(
+                PoeModule::create_claim(Origin::signed(1), vec![3], None, Some(metadata)),
+                Error::<Test>::BadMetadata
+            );
+        }
+
+        // the metadata follows the claim
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+        assert_eq!(PoeModule::claim_metadata(&claim), Some(metadata()));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 58);
+    })
+}
+
+#[test]
+fn set_claim_metadata_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_eq!(PoeModule::claim_metadata(&claim), None);
+
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())));
+        assert_eq!(PoeModule::claim_metadata(&claim), Some(metadata()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimMetadataUpdated(1, claim.clone()))
+        );
+        // the deposit of the metadata is topped up
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().deposit, 58);
+        assert_eq!(Balances::reserved_balance(1), 58);
+
+        // shorter metadata refunds the difference
+        let short = ClaimMetadata { tags: vec![], ..metadata() };
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(short)));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().deposit, 49);
+        assert_eq!(Balances::reserved_balance(1), 49);
+
+        // the top-up has to be affordable
+        Balances::make_free_balance_be(&1, 5);
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())),
+            Error::<Test>::InsufficientBalance
+        );
+        Balances::make_free_balance_be(&1, 100 - 49);
+
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(2), claim.clone(), None),
+            Error::<Test>::NotProofOwner
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), vec![3], None),
+            Error::<Test>::NoSuchProof
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(
+                Origin::signed(1), claim.clone(), Some(ClaimMetadata { title: vec![0; 9], ..metadata() })
+            ),
+            Error::<Test>::BadMetadata
+        );
+
+        // None clears the metadata
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), None));
+        assert_eq!(PoeModule::claim_metadata(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        // revoking the claim drops its metadata
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())));
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::claim_metadata(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+    })
+}
+
+#[test]
+fn migrate_to_v2_should_translate_legacy_claims() {
+    new_test_ext().execute_with(|| {
+        // claims stored at blocks 2 and 4 in the V1_0_0 layout
+        let claim = vec![0,1,2];
+        let other = vec![3,4];
+        frame_support::storage::unhashed::put(
+            &Proofs::<Test>::hashed_key_for(&claim),
+            &(1u64, 2u64),
+        );
+        frame_support::storage::unhashed::put(
+            &Proofs::<Test>::hashed_key_for(&other),
+            &(2u64, 4u64),
+        );
+
+        // upgrade at block 5, six seconds per block
+        run_to_block(5);
+        Timestamp::set_timestamp(30_000);
+        // the checks around the upgrade pass only once the claims are translated
+        assert_ok!(migrations::pre_migrate_to_v2::<Test>());
+        assert_eq!(
+            migrations::post_migrate_to_v2::<Test>(),
+            Err("storage version was not bumped to V2_0_0")
+        );
+        migrations::migrate_to_v2::<Test>();
+        assert_ok!(migrations::post_migrate_to_v2::<Test>());
+
+        assert_eq!(Proofs::<Test>::get(&claim), Some(ClaimInfo {
+            owner: 1,
+            block_number: 2,
+            moment: 12_000,
+            created_at: 12_000,
+            deposit: 0,
+            expiry: None,
+        }));
+        assert_eq!(Proofs::<Test>::get(&other).unwrap().moment, 24_000);
+        assert_eq!(StorageVersion::<Test>::get(), Releases::V2_0_0);
+        // the claims are indexed and keep their chain of custody
+        assert_eq!(PoeModule::claims_by_owner(1), vec![claim.clone()]);
+        assert_eq!(PoeModule::claims_by_owner(2), vec![other.clone()]);
+        assert_eq!(PoeModule::claim_history(&other), vec![(2, 4)]);
+
+        // the migration only runs once
+        Timestamp::set_timestamp(36_000);
+        migrations::migrate_to_v2::<Test>();
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().moment, 12_000);
+
+        // legacy claims paid no deposit
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(Proofs::<Test>::get(&claim), None);
+        assert_eq!(Balances::free_balance(1), 100);
+    })
+}
+
+fn owner_signature(owner: u64, proof: &[u8], expiry: Option<u64>, nonce: u64, deadline: u64) -> TestSignature {
+    TestSignature(owner, PoeModule::delegated_claim_payload(proof, expiry, nonce, deadline))
+}
+
+#[test]
+fn create_claim_for_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let signature = owner_signature(3, &claim, None, 0, 5);
+
+        assert_ok!(PoeModule::create_claim_for(Origin::signed(1), 3, claim.clone(), None, 5, signature.clone()));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 3);
+        assert_eq!(PoeModule::nonce_of(3), 1);
+        assert_eq!(PoeModule::claims_by_owner(3), vec![claim.clone()]);
+        // the deposit is reserved from the relayer
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(PoeModule::claim_depositor(&claim), Some(1));
+
+        // the same signature cannot be replayed
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim.clone()));
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(PoeModule::claim_depositor(&claim), None);
+        assert_noop!(
+            PoeModule::create_claim_for(Origin::signed(1), 3, claim.clone(), None, 5, signature),
+            Error::<Test>::InvalidSignature
+        );
+        assert_ok!(PoeModule::create_claim_for(
+            Origin::signed(1), 3, claim.clone(), None, 5, owner_signature(3, &claim, None, 1, 5)
+        ));
+    })
+}
+
+#[test]
+fn create_claim_for_deposit_should_stay_with_relayer() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+
+        // account 4 holds no funds, the relayer reserves the deposit
+        assert_ok!(PoeModule::create_claim_for(
+            Origin::signed(1), 4, claim.clone(), None, 5, owner_signature(4, &claim, None, 0, 5)
+        ));
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        // the deposit stays with the relayer when the claim changes hands
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 4));
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(4), claim.clone(), 2));
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(PoeModule::claim_depositor(&claim), Some(1));
+
+        // and is refunded to the relayer when the claim is removed
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Balances::free_balance(2), 100);
+
+        // the owner takes the whole deposit over when setting metadata
+        assert_ok!(PoeModule::create_claim_for(
+            Origin::signed(1), 2, claim.clone(), None, 5, owner_signature(2, &claim, None, 0, 5)
+        ));
+        assert_ok!(PoeModule::set_claim_metadata(Origin::signed(2), claim.clone(), Some(metadata())));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 58);
+        assert_eq!(PoeModule::claim_depositor(&claim), None);
+    })
+}
+
+#[test]
+fn create_claim_for_failed_when_signature_invalid() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+
+        // the signed payload has to match the call
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), None, 5, owner_signature(2, &claim, None, 0, 5)
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), Some(4), 5, owner_signature(3, &claim, None, 0, 5)
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, vec![9], None, 5, owner_signature(3, &claim, None, 0, 5)
+            ),
+            Error::<Test>::InvalidSignature
+        );
+        // the signed payload has to be tagged with the pallet and the genesis hash
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), None, 5,
+                TestSignature(3, (&claim[..], None::<u64>, 0u64, 5u64).encode())
+            ),
+            Error::<Test>::InvalidSignature
+        );
+
+        // the deadline has passed
+        run_to_block(6);
+        assert_noop!(
+            PoeModule::create_claim_for(
+                Origin::signed(1), 3, claim.clone(), None, 5, owner_signature(3, &claim, None, 0, 5)
+            ),
+            Error::<Test>::SignatureExpired
+        );
+        assert_eq!(PoeModule::nonce_of(3), 0);
+    })
+}
+
+#[test]
+fn commit_and_reveal_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let salt = vec![7,7];
+        let commitment = PoeModule::commitment_of(&claim, &salt, &1);
+        assert_eq!(commitment, BlakeTwo256::hash_of(&(claim.clone(), salt.clone(), 1u64)));
+        // the parts are encoded separately, so moving bytes between proof and salt changes the
+        // commitment
+        assert_ne!(commitment, PoeModule::commitment_of(&[0,1], &[2,7,7], &1));
+
+        // the commitment does not publish the proof
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+        assert_eq!(PoeModule::commitments(commitment).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(1), 42);
+        assert!(!Proofs::<Test>::contains_key(&claim));
+        assert_noop!(
+            PoeModule::commit_claim(Origin::signed(2), commitment),
+            Error::<Test>::CommitmentAlreadyExists
+        );
+
+        // the revealed claim keeps the block of the commitment
+        run_to_block(4);
+        assert_ok!(PoeModule::reveal_claim(Origin::signed(1), claim.clone(), salt));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().block_number, 1);
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(PoeModule::commitments(commitment), None);
+        assert_eq!(Balances::reserved_balance(1), 13);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimRevealed(1, commitment, claim))
+        );
+    })
+}
+
+#[test]
+fn reveal_claim_failed_when_commitment_invalid() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let salt = vec![7,7];
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), PoeModule::commitment_of(&claim, &salt, &1)));
+
+        // a wrong salt or sender matches no commitment
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(1), claim.clone(), vec![8]),
+            Error::<Test>::NoSuchCommitment
+        );
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(2), claim.clone(), salt.clone()),
+            Error::<Test>::NoSuchCommitment
+        );
+
+        // the reveal window has passed
+        run_to_block(7);
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(1), claim, salt),
+            Error::<Test>::RevealWindowClosed
+        );
+    })
+}
+
+#[test]
+fn reveal_claim_failed_when_commitment_copied() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let salt = vec![7,7];
+        // account 1 front-runs account 2 with the commitment account 2 was about to make
+        let commitment = PoeModule::commitment_of(&claim, &salt, &2);
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![9], None, None));
+
+        // the commitment only counts for the account which made it
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(2), claim.clone(), salt.clone()),
+            Error::<Test>::NoSuchCommitment
+        );
+        assert_noop!(
+            PoeModule::reveal_claim(Origin::signed(1), claim.clone(), salt),
+            Error::<Test>::NoSuchCommitment
+        );
+        assert_eq!(Balances::reserved_balance(1), 42);
+        assert_eq!(Balances::reserved_balance(2), 11);
+
+        // the deposit is refunded to the account which made the commitment
+        run_to_block(7);
+        assert_ok!(PoeModule::reap_commitment(Origin::signed(2), commitment));
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn reap_commitment_should_work() {
+    new_test_ext().execute_with(|| {
+        let commitment = PoeModule::commitment_of(&[0,1,2], &[7,7], &1);
+        assert_ok!(PoeModule::commit_claim(Origin::signed(1), commitment));
+
+        // the commitment can still be revealed
+        run_to_block(6);
+        assert_noop!(
+            PoeModule::reap_commitment(Origin::signed(2), commitment),
+            Error::<Test>::RevealWindowOpen
+        );
+
+        // anyone can reap it, refunding the owner
+        run_to_block(7);
+        assert_ok!(PoeModule::reap_commitment(Origin::signed(2), commitment));
+        assert_eq!(PoeModule::commitments(commitment), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::CommitmentReaped(1, commitment))
+        );
+        assert_noop!(
+            PoeModule::reap_commitment(Origin::signed(2), commitment),
+            Error::<Test>::NoSuchCommitment
+        );
+    })
+}
+
+#[test]
+fn attest_claim_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // only root registers notaries
+        assert_noop!(PoeModule::add_notary(Origin::signed(1), 2), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(PoeModule::add_notary(Origin::root(), 2));
+        assert_ok!(PoeModule::add_notary(Origin::root(), 3));
+        assert_eq!(PoeModule::notary_count(), 2);
+        assert_noop!(PoeModule::add_notary(Origin::root(), 2), Error::<Test>::AlreadyNotary);
+        // the registry is full
+        assert_noop!(PoeModule::add_notary(Origin::root(), 4), Error::<Test>::TooManyNotaries);
+
+        // only notaries may attest
+        assert_noop!(PoeModule::attest_claim(Origin::signed(1), claim.clone()), Error::<Test>::NotNotary);
+        assert_noop!(PoeModule::attest_claim(Origin::signed(2), vec![9]), Error::<Test>::NoSuchProof);
+
+        assert_ok!(PoeModule::attest_claim(Origin::signed(2), claim.clone()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimAttested(2, claim.clone()))
+        );
+        assert!(!PoeModule::is_verified(&claim));
+        // a notary attests once
+        assert_noop!(PoeModule::attest_claim(Origin::signed(2), claim.clone()), Error::<Test>::AlreadyAttested);
+
+        // the threshold verifies the claim
+        assert_ok!(PoeModule::attest_claim(Origin::signed(3), claim.clone()));
+        assert_eq!(PoeModule::attestations(&claim), vec![2, 3]);
+        assert!(PoeModule::is_verified(&claim));
+
+        // removed notaries no longer count
+        assert_ok!(PoeModule::remove_notary(Origin::root(), 3));
+        assert_noop!(PoeModule::remove_notary(Origin::root(), 3), Error::<Test>::NotNotary);
+        assert_eq!(PoeModule::notary_count(), 1);
+        assert!(!PoeModule::is_verified(&claim));
+
+        // revoking the claim clears its attestations
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()));
+        assert_eq!(PoeModule::attestations(&claim), Vec::<u64>::new());
+    })
+}
+
+#[test]
+fn challenge_claim_should_lock_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        let evidence = H256::repeat_byte(1);
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // the owner cannot challenge their own claim
+        assert_noop!(
+            PoeModule::challenge_claim(Origin::signed(1), claim.clone(), evidence),
+            Error::<Test>::ChallengerIsOwner
+        );
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), evidence));
+        assert_eq!(Balances::reserved_balance(2), 20);
+        assert_eq!(PoeModule::dispute(&claim).unwrap().challenger, 2);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimChallenged(2, claim.clone(), evidence))
+        );
+        // one dispute at a time
+        assert_noop!(
+            PoeModule::challenge_claim(Origin::signed(3), claim.clone(), evidence),
+            Error::<Test>::ClaimDisputed
+        );
+
+        // the claim is locked while disputed
+        assert_ok!(PoeModule::approve_sender(Origin::signed(3), 1));
+        assert_noop!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()), Error::<Test>::ClaimDisputed);
+        assert_noop!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 3), Error::<Test>::ClaimDisputed);
+        assert_noop!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 3), Error::<Test>::ClaimDisputed);
+    })
+}
+
+#[test]
+fn resolve_dispute_should_uphold_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        // only root resolves disputes
+        assert_noop!(
+            PoeModule::resolve_dispute(Origin::signed(1), claim.clone(), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PoeModule::resolve_dispute(Origin::root(), vec![9], true),
+            Error::<Test>::NotDisputed
+        );
+
+        // upholding slashes the bond of the challenger
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), true));
+        assert_eq!(PoeModule::dispute(&claim), None);
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 1);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 80);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::DisputeUpheld(1, 2, claim.clone()))
+        );
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim));
+    })
+}
+
+#[test]
+fn resolve_dispute_should_reassign_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        // reassigning slashes the deposit of the owner and keeps the date
+        run_to_block(3);
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), false));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap(), ClaimInfo {
+            owner: 2,
+            block_number: 1,
+            moment: 6000,
+            created_at: 6000,
+            deposit: 20,
+            expiry: None,
+        });
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(2), 20);
+        assert_eq!(PoeModule::claims_by_owner(1), Vec::<Vec<u8>>::new());
+        assert_eq!(PoeModule::claims_by_owner(2), vec![claim.clone()]);
+        assert_eq!(PoeModule::claim_history(&claim), vec![(1, 1), (2, 3)]);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimReassigned(1, 2, claim.clone()))
+        );
+
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(2), claim));
+        assert_eq!(Balances::free_balance(2), 100);
+    })
+}
+
+#[test]
+fn disputed_claims_should_not_expire() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(3), None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        // the claim outlives its expiry while the challenge is open
+        run_to_block(3);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        assert_eq!(Balances::reserved_balance(2), 20);
+
+        // and is pruned in the block after the dispute is resolved
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), true));
+        assert_eq!(PoeModule::claim_expiries(4), vec![claim.clone()]);
+        run_to_block(4);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn resolve_dispute_should_void_claim_when_challenger_full() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![5], None, None));
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![6], None, None));
+
+        // the challenger cannot own another claim, so the claim is removed and the bond refunded
+        assert_ok!(PoeModule::resolve_dispute(Origin::root(), claim.clone(), false));
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(PoeModule::dispute(&claim), None);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 87);
+        assert_eq!(Balances::reserved_balance(2), 22);
+        assert_eq!(Balances::free_balance(2), 78);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimVoided(1, 2, claim))
+        );
+    })
+}
+
+#[test]
+fn force_transfer_should_drop_dispute() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::challenge_claim(Origin::signed(2), claim.clone(), H256::repeat_byte(1)));
+
+        assert_ok!(PoeModule::force_transfer(Origin::root(), claim.clone(), 3));
+        assert_eq!(PoeModule::dispute(&claim), None);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 100);
+
+        // the new owner is free to move the claim
+        assert_ok!(PoeModule::revoke_claim(Origin::signed(3), claim));
+    })
+}
+
+#[test]
+fn freeze_claim_should_lock_claim() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+
+        // only ForceOrigin freezes claims
+        assert_noop!(PoeModule::freeze_claim(Origin::signed(1), claim.clone()), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(PoeModule::freeze_claim(Origin::root(), vec![9]), Error::<Test>::NoSuchProof);
+        assert_ok!(PoeModule::freeze_claim(Origin::root(), claim.clone()));
+        assert!(PoeModule::is_frozen(&claim));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimFrozen(claim.clone()))
+        );
+        assert_noop!(PoeModule::freeze_claim(Origin::root(), claim.clone()), Error::<Test>::AlreadyFrozen);
+
+        // the owner cannot move a frozen claim
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+        assert_noop!(PoeModule::revoke_claim(Origin::signed(1), claim.clone()), Error::<Test>::ClaimIsFrozen);
+        assert_noop!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2), Error::<Test>::ClaimIsFrozen);
+        assert_noop!(PoeModule::offer_claim(Origin::signed(1), claim.clone(), 2), Error::<Test>::ClaimIsFrozen);
+
+        // thawing restores the owner's rights
+        assert_ok!(PoeModule::thaw_claim(Origin::root(), claim.clone()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimThawed(claim.clone()))
+        );
+        assert_noop!(PoeModule::thaw_claim(Origin::root(), claim.clone()), Error::<Test>::NotFrozen);
+        assert_ok!(PoeModule::transfer_claim(Origin::signed(1), claim.clone(), 2));
+    })
+}
+
+#[test]
+fn frozen_claims_should_not_expire() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), Some(5), None));
+        assert_ok!(PoeModule::freeze_claim(Origin::root(), claim.clone()));
+
+        // the owner cannot make a frozen claim expire sooner, nor touch its metadata
+        assert_noop!(
+            PoeModule::renew_claim(Origin::signed(1), claim.clone(), Some(2)),
+            Error::<Test>::ClaimIsFrozen
+        );
+        assert_noop!(
+            PoeModule::set_claim_metadata(Origin::signed(1), claim.clone(), Some(metadata())),
+            Error::<Test>::ClaimIsFrozen
+        );
+
+        // a frozen claim outlives its expiry
+        run_to_block(5);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), true);
+        assert_eq!(Balances::reserved_balance(1), 13);
+
+        // and is pruned in the block after it is thawed
+        assert_ok!(PoeModule::thaw_claim(Origin::root(), claim.clone()));
+        assert_eq!(PoeModule::claim_expiries(6), vec![claim.clone()]);
+        run_to_block(6);
+        assert_eq!(Proofs::<Test>::contains_key(&claim), false);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    })
+}
+
+#[test]
+fn force_revoke_and_transfer_should_work() {
+    new_test_ext().execute_with(|| {
+        let claim = vec![0,1,2];
+        assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone(), None, None));
+        assert_ok!(PoeModule::freeze_claim(Origin::root(), claim.clone()));
+
+        // force_transfer needs no approval of the recipient
+        assert_noop!(
+            PoeModule::force_transfer(Origin::signed(1), claim.clone(), 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(PoeModule::force_transfer(Origin::root(), claim.clone(), 2));
+        assert_eq!(Proofs::<Test>::get(&claim).unwrap().owner, 2);
+        assert_eq!(Balances::reserved_balance(2), 13);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimForceTransferred(1, 2, claim.clone()))
+        );
+
+        // force_revoke refunds the owner
+        assert_noop!(PoeModule::force_revoke(Origin::root(), vec![9]), Error::<Test>::NoSuchProof);
+        assert_ok!(PoeModule::force_revoke(Origin::root(), claim.clone()));
+        assert!(!Proofs::<Test>::contains_key(&claim));
+        assert!(!PoeModule::is_frozen(&claim));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::ClaimForceRevoked(2, claim))
+        );
+    })
+}
+
+#[test]
+fn create_claims_should_work() {
+    new_test_ext().execute_with(|| {
+        let claims = vec![vec![0,1], vec![0,2]];
+
+        // batches are bounded by MaxBatchSize
+        assert_noop!(
+            PoeModule::create_claims(Origin::signed(1), vec![vec![0], vec![1], vec![2]]),
+            Error::<Test>::BatchTooLarge
+        );
+
+        assert_ok!(PoeModule::create_claims(Origin::signed(1), claims.clone()));
+        assert_eq!(PoeModule::claims_by_owner(1), claims);
+        assert_eq!(Balances::reserved_balance(1), 24);
+    })
+}
+
+#[test]
+fn create_claims_failed_should_roll_back() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PoeModule::create_claim(Origin::signed(2), vec![0,2], None, None));
+
+        // the second item is already claimed, so the whole batch is rolled back
+        assert_err!(
+            PoeModule::create_claims(Origin::signed(1), vec![vec![0,1], vec![0,2]]),
+            Error::<Test>::BatchItemFailed
+        );
+        assert!(!Proofs::<Test>::contains_key(&vec![0,1]));
+        assert_eq!(PoeModule::claims_by_owner(1), Vec::<Vec<u8>>::new());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            System::events().last().unwrap().event,
+            mock::Event::pallet_poe(crate::Event::<Test>::BatchInterrupted(
+                1, Error::<Test>::ProofAlreadyClaimed.into()
+            ))
+        );
+    })
+}
+
+#[test]
+fn revoke_and_transfer_claims_should_work() {
+    new_test_ext().execute_with(|| {
+        let claims = vec![vec![0,1], vec![0,2]];
+        assert_ok!(PoeModule::create_claims(Origin::signed(1), claims.clone()));
+        assert_ok!(PoeModule::approve_sender(Origin::signed(2), 1));
+
+        // transfer both claims at once
+        assert_err!(
+            PoeModule::transfer_claims(Origin::signed(1), vec![(vec![0,1], 2), (vec![0,2], 3)]),
+            Error::<Test>::BatchItemFailed
+        );
+        assert_eq!(PoeModule::claims_by_owner(1), claims);
+        assert_ok!(PoeModule::transfer_claims(Origin::signed(1), vec![(vec![0,1], 2), (vec![0,2], 2)]));
+        assert_eq!(PoeModule::claims_by_owner(2), claims);
+
+        // revoke both claims at once
+        assert_err!(
+            PoeModule::revoke_claims(Origin::signed(2), vec![vec![0,1], vec![9]]),
+            Error::<Test>::BatchItemFailed
+        );
+        assert!(Proofs::<Test>::contains_key(&vec![0,1]));
+        assert_ok!(PoeModule::revoke_claims(Origin::signed(2), claims.clone()));
+        assert_eq!(PoeModule::claims_by_owner(2), Vec::<Vec<u8>>::new());
+        assert_eq!(Balances::reserved_balance(2), 0);
+    })
+}
+
+#[test]
+fn genesis_config_should_seed_claims() {
+    new_test_ext_with_claims(vec![(vec![0,1], 1), (vec![0,2], 2)]).execute_with(|| {
+        // genesis claims reserve the usual deposit and date from block 0
+        assert_eq!(Proofs::<Test>::get(&vec![0,1]).unwrap(), ClaimInfo {
+            owner: 1,
+            block_number: 0,
+            moment: 0,
+            created_at: 0,
+            deposit: 12,
+            expiry: None,
+        });
+        assert_eq!(Proofs::<Test>::get(&vec![0,2]).unwrap().owner, 2);
+        assert_eq!(PoeModule::claims_by_owner(1), vec![vec![0,1]]);
+        assert_eq!(Balances::reserved_balance(1), 12);
+        assert_eq!(StorageVersion::<Test>::get(), Releases::V2_0_0);
+    })
+}
+
+#[test]
+#[should_panic(expected = "duplicate claim in genesis")]
+fn genesis_config_should_reject_duplicates() {
+    // proofs must be unique
+    new_test_ext_with_claims(vec![(vec![0,1], 1), (vec![0,1], 2)]);
+}
+
+#[test]
+#[should_panic(expected = "genesis claim is longer than StringLimit")]
+fn genesis_config_should_reject_long_proofs() {
+    // proofs are limited by StringLimit
+    new_test_ext_with_claims(vec![(vec![0; StringLimit::get() as usize + 1], 1)]);
+}
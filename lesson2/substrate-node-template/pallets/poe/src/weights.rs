@@ -0,0 +1,456 @@
+//! Hand-written placeholder weights for pallet_poe.
+//!
+//! These figures were NOT produced by the benchmark CLI. They are rough, deliberately rounded
+//! guesses from the storage accesses of each call, kept in the layout of the generated file so that
+//! it can be replaced as a whole. Replace this file with the output of the command below on
+//! reference hardware before relying on the fees it charges.
+
+// Generate with:
+// ./target/release/node-template
+// benchmark
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_poe
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/poe/src/weights.rs
+
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim(p: u32, m: u32, ) -> Weight;
+	fn create_claims(n: u32, ) -> Weight;
+	fn revoke_claims(n: u32, ) -> Weight;
+	fn transfer_claims(n: u32, ) -> Weight;
+	fn create_claim_for(p: u32, ) -> Weight;
+	fn create_cosigned_claim(p: u32, o: u32, ) -> Weight;
+	fn approve_claim(o: u32, ) -> Weight;
+	fn revoke_claim(p: u32, ) -> Weight;
+	fn transfer_claim(p: u32, ) -> Weight;
+	fn offer_claim(p: u32, ) -> Weight;
+	fn accept_claim(p: u32, ) -> Weight;
+	fn cancel_offer(p: u32, ) -> Weight;
+	fn approve_sender() -> Weight;
+	fn unapprove_sender() -> Weight;
+	fn renew_claim(p: u32, ) -> Weight;
+	fn set_claim_metadata(p: u32, m: u32, ) -> Weight;
+	fn anchor_batch() -> Weight;
+	fn revoke_batch() -> Weight;
+	fn claim_from_batch(d: u32, ) -> Weight;
+	fn commit_claim() -> Weight;
+	fn reveal_claim(p: u32, s: u32, ) -> Weight;
+	fn reap_commitment() -> Weight;
+	fn add_notary() -> Weight;
+	fn remove_notary() -> Weight;
+	fn attest_claim(p: u32, ) -> Weight;
+	fn challenge_claim(p: u32, ) -> Weight;
+	fn resolve_dispute(p: u32, ) -> Weight;
+	fn force_revoke(p: u32, ) -> Weight;
+	fn force_transfer(p: u32, ) -> Weight;
+	fn freeze_claim(p: u32, ) -> Weight;
+	fn thaw_claim(p: u32, ) -> Weight;
+	fn create_hashed_claim() -> Weight;
+	fn hash_and_claim(c: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_poe, until benchmarked ones replace them.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_claim(p: u32, m: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((3_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn create_claims(n: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((70_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	fn revoke_claims(n: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((70_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((15 as Weight).saturating_mul(n as Weight)))
+	}
+	fn transfer_claims(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((100_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((8 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(n as Weight)))
+	}
+	fn create_claim_for(p: u32, ) -> Weight {
+		(200_000_000 as Weight)
+			.saturating_add((5_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn create_cosigned_claim(p: u32, o: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((2_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn approve_claim(o: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((900_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_claim(p: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+	}
+	fn transfer_claim(p: u32, ) -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add((6_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn offer_claim(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn accept_claim(p: u32, ) -> Weight {
+		(100_000_000 as Weight)
+			.saturating_add((6_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn cancel_offer(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn approve_sender() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn unapprove_sender() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn renew_claim(p: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn set_claim_metadata(p: u32, m: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((3_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn anchor_batch() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn revoke_batch() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn claim_from_batch(d: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((4_000_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn commit_claim() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn reveal_claim(p: u32, s: u32, ) -> Weight {
+		(100_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn reap_commitment() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn add_notary() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn remove_notary() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn attest_claim(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn challenge_claim(p: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn resolve_dispute(p: u32, ) -> Weight {
+		(200_000_000 as Weight)
+			.saturating_add((7_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	fn force_revoke(p: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(15 as Weight))
+	}
+	fn force_transfer(p: u32, ) -> Weight {
+		(200_000_000 as Weight)
+			.saturating_add((6_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn freeze_claim(p: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_claim(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn create_hashed_claim() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn hash_and_claim(c: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_claim(p: u32, m: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((3_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn create_claims(n: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((70_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	fn revoke_claims(n: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((70_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((15 as Weight).saturating_mul(n as Weight)))
+	}
+	fn transfer_claims(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((100_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((8 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(n as Weight)))
+	}
+	fn create_claim_for(p: u32, ) -> Weight {
+		(200_000_000 as Weight)
+			.saturating_add((5_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn create_cosigned_claim(p: u32, o: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((2_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn approve_claim(o: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((900_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke_claim(p: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
+	}
+	fn transfer_claim(p: u32, ) -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add((6_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn offer_claim(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn accept_claim(p: u32, ) -> Weight {
+		(100_000_000 as Weight)
+			.saturating_add((6_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn cancel_offer(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn approve_sender() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn unapprove_sender() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn renew_claim(p: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn set_claim_metadata(p: u32, m: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((3_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn anchor_batch() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn revoke_batch() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn claim_from_batch(d: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((4_000_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn commit_claim() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn reveal_claim(p: u32, s: u32, ) -> Weight {
+		(100_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn reap_commitment() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn add_notary() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn remove_notary() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn attest_claim(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn challenge_claim(p: u32, ) -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn resolve_dispute(p: u32, ) -> Weight {
+		(200_000_000 as Weight)
+			.saturating_add((7_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	fn force_revoke(p: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(15 as Weight))
+	}
+	fn force_transfer(p: u32, ) -> Weight {
+		(200_000_000 as Weight)
+			.saturating_add((6_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn freeze_claim(p: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_claim(p: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn create_hashed_claim() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn hash_and_claim(c: u32, ) -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+}
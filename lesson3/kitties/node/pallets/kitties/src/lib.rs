@@ -2,23 +2,33 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::Randomness};
 	use frame_system::pallet_prelude::*;
 	use codec::{Encode, Decode};
 	use sp_io::hashing::blake2_128;
-	use sp_runtime::{traits::One};
+	use sp_runtime::{traits::{One, Zero}};
+	use sp_std::{vec, vec::Vec};
 
 	#[derive(Encode, Decode)]
 	pub struct Kitty(pub [u8;16]);
 
-	type KittyIndex = u32;
+	pub type KittyIndex = u32;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+		/// The blocks a kitty of each generation has to wait between breedings, the last entry
+		/// applying to all later generations.
+		type BreedingCooldowns: Get<Vec<Self::BlockNumber>>;
 	}
 
 	#[pallet::pallet]
@@ -29,7 +39,7 @@ pub mod pallet {
 	#[pallet::metadata(T::AccountId = "AccountId")]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		KittyCreate(T::AccountId, KittyIndex),
+		KittyCreate(T::AccountId, KittyIndex, Option<(KittyIndex, KittyIndex)>),
 		KittyTransfer(T::AccountId, T::AccountId, KittyIndex),
 	}
 
@@ -45,12 +55,30 @@ pub mod pallet {
 	#[pallet::getter(fn owner)]
 	pub type Owner<T: Config> = StorageMap<_, Blake2_128Concat, KittyIndex, Option<T::AccountId>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn parents_of)]
+	pub type Parents<T> = StorageMap<_, Blake2_128Concat, KittyIndex, (KittyIndex, KittyIndex), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn generation_of)]
+	pub type Generation<T> = StorageMap<_, Blake2_128Concat, KittyIndex, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn children_of)]
+	pub type Children<T> = StorageMap<_, Blake2_128Concat, KittyIndex, Vec<KittyIndex>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn ready_at)]
+	pub type BreedingReadyAt<T: Config> = StorageMap<_, Blake2_128Concat, KittyIndex, T::BlockNumber, ValueQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		KittiesCountOverflow,
 		NotOwner,
 		SameParentIndex,
 		InvalidKittyIndex,
+		KittyOnCooldown,
+		InvalidPairing,
 	}
 
 	#[pallet::call]
@@ -75,7 +103,7 @@ pub mod pallet {
 
 			KittiesCount::<T>::put(kitty_id + 1);
 
-			Self::deposit_event(Event::KittyCreate(who, kitty_id));
+			Self::deposit_event(Event::KittyCreate(who, kitty_id, None));
 
 			Ok(())
 		}
@@ -105,6 +133,14 @@ pub mod pallet {
 			let kitty1 = Self::kitties(kitty_id_1).ok_or(Error::<T>::InvalidKittyIndex)?;
 			let kitty2 = Self::kitties(kitty_id_2).ok_or(Error::<T>::InvalidKittyIndex)?;
 
+			ensure!(Owner::<T>::get(kitty_id_1) == Some(who.clone()), Error::<T>::NotOwner);
+			ensure!(Owner::<T>::get(kitty_id_2) == Some(who.clone()), Error::<T>::NotOwner);
+
+			ensure!(Self::can_pair(kitty_id_1, kitty_id_2), Error::<T>::InvalidPairing);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(Self::ready_at(kitty_id_1) <= now && Self::ready_at(kitty_id_2) <= now, Error::<T>::KittyOnCooldown);
+
 				let kitty_id = match Self::kitties_count() {
 				Some(id) => {
 					ensure!(id != KittyIndex::max_value(), Error::<T>::KittiesCountOverflow);
@@ -127,15 +163,69 @@ pub mod pallet {
 
 			Owner::<T>::insert(kitty_id, Some(who.clone()));
 
+			Self::record_lineage(kitty_id, kitty_id_1, kitty_id_2);
+			BreedingReadyAt::<T>::insert(kitty_id_1, now + Self::cooldown_of(kitty_id_1));
+			BreedingReadyAt::<T>::insert(kitty_id_2, now + Self::cooldown_of(kitty_id_2));
+
 			KittiesCount::<T>::put(kitty_id + 1);
 
-			Self::deposit_event(Event::KittyCreate(who, kitty_id));
+			Self::deposit_event(Event::KittyCreate(who, kitty_id, Some((kitty_id_1, kitty_id_2))));
 
 			Ok(())
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The ancestors of `kitty_id` up to `depth` generations back, nearest generation first.
+		pub fn ancestors(kitty_id: KittyIndex, depth: u32) -> Vec<KittyIndex> {
+			let mut ancestors = Vec::new();
+			let mut generation = vec![kitty_id];
+
+			for _ in 0..depth {
+				let mut parents = Vec::new();
+				for id in generation.iter() {
+					if let Some((parent_1, parent_2)) = Self::parents_of(id) {
+						for parent in [parent_1, parent_2].iter() {
+							if !ancestors.contains(parent) {
+								ancestors.push(*parent);
+								parents.push(*parent);
+							}
+						}
+					}
+				}
+				if parents.is_empty() {
+					break;
+				}
+				generation = parents;
+			}
+
+			ancestors
+		}
+
+		fn can_pair(kitty_id_1: KittyIndex, kitty_id_2: KittyIndex) -> bool {
+			match (Self::parents_of(kitty_id_1), Self::parents_of(kitty_id_2)) {
+				(Some((a, b)), _) if a == kitty_id_2 || b == kitty_id_2 => false,
+				(_, Some((a, b))) if a == kitty_id_1 || b == kitty_id_1 => false,
+				(Some((a_1, b_1)), Some((a_2, b_2))) => a_1 != a_2 && a_1 != b_2 && b_1 != a_2 && b_1 != b_2,
+				_ => true,
+			}
+		}
+
+		fn cooldown_of(kitty_id: KittyIndex) -> T::BlockNumber {
+			let schedule = T::BreedingCooldowns::get();
+			let generation = Self::generation_of(kitty_id) as usize;
+			schedule.get(generation).or_else(|| schedule.last()).cloned().unwrap_or_else(Zero::zero)
+		}
+
+		fn record_lineage(kitty_id: KittyIndex, parent_1: KittyIndex, parent_2: KittyIndex) {
+			let generation = Self::generation_of(parent_1).max(Self::generation_of(parent_2)).saturating_add(1);
+
+			Parents::<T>::insert(kitty_id, (parent_1, parent_2));
+			Generation::<T>::insert(kitty_id, generation);
+			Children::<T>::append(parent_1, kitty_id);
+			Children::<T>::append(parent_2, kitty_id);
+		}
+
 		fn random_value(sender: &T::AccountId) -> [u8; 16] {
 			let payload = (
 				T::Randomness::random_seed(),
@@ -0,0 +1,130 @@
+use crate::{Error, mock::*};
+use frame_support::{assert_ok, assert_noop};
+
+#[test]
+fn create_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(KittiesModule::kitties_count(), Some(1));
+		assert_eq!(KittiesModule::owner(0), Some(1));
+		assert!(KittiesModule::kitties(0).is_some());
+	});
+}
+
+#[test]
+fn transfer_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::transfer(Origin::signed(1), 2, 0));
+		assert_eq!(KittiesModule::owner(0), Some(2));
+
+		// Only the owner can transfer the kitty.
+		assert_noop!(
+			KittiesModule::transfer(Origin::signed(1), 3, 0),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn breed_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1));
+
+		assert_eq!(KittiesModule::owner(2), Some(1));
+		assert_eq!(KittiesModule::parents_of(2), Some((0, 1)));
+		assert_eq!(KittiesModule::generation_of(2), 1);
+		assert_eq!(KittiesModule::children_of(0), vec![2]);
+		assert_eq!(KittiesModule::ancestors(2, 1), vec![0, 1]);
+
+		// Both parents wait for the cooldown of their generation.
+		assert_eq!(KittiesModule::ready_at(0), 6);
+		assert_eq!(KittiesModule::ready_at(1), 6);
+	});
+}
+
+#[test]
+fn breed_failed_when_not_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+		assert_ok!(KittiesModule::create(Origin::signed(2)));
+
+		// Either parent may belong to someone else.
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 0, 1),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 0),
+			Error::<Test>::NotOwner
+		);
+
+		// Ownership is checked before the cooldown.
+		assert_ok!(KittiesModule::breed(Origin::signed(2), 1, 2));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 2),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn breed_failed_on_cooldown() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1));
+
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 0, 1),
+			Error::<Test>::KittyOnCooldown
+		);
+
+		// The pair is ready again once the cooldown is over.
+		System::set_block_number(6);
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1));
+
+		// Later generations wait longer.
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 2, 4));
+		assert_eq!(KittiesModule::ready_at(2), 16);
+		assert_eq!(KittiesModule::ready_at(4), 11);
+	});
+}
+
+#[test]
+fn breed_failed_on_invalid_pairing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 0, 0),
+			Error::<Test>::SameParentIndex
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 0, 9),
+			Error::<Test>::InvalidKittyIndex
+		);
+
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1));
+		System::set_block_number(6);
+		assert_ok!(KittiesModule::breed(Origin::signed(1), 0, 1));
+
+		// A kitty cannot be bred with its parent, nor with its sibling.
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 2, 0),
+			Error::<Test>::InvalidPairing
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 1, 3),
+			Error::<Test>::InvalidPairing
+		);
+		assert_noop!(
+			KittiesModule::breed(Origin::signed(1), 2, 3),
+			Error::<Test>::InvalidPairing
+		);
+	});
+}
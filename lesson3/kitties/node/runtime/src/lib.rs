@@ -273,9 +273,14 @@ impl pallet_template::Config for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	pub BreedingCooldowns: Vec<BlockNumber> = sp_std::vec![MINUTES, 5 * MINUTES, 30 * MINUTES, 2 * HOURS, 8 * HOURS, DAYS];
+}
+
 impl pallet_kitties::Config for Runtime {
 	type Event = Event;
 	type Randomness = RandomnessCollectiveFlip;
+	type BreedingCooldowns = BreedingCooldowns;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -438,6 +443,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_kitties_rpc_runtime_api::KittiesApi<Block, pallet_kitties::KittyIndex> for Runtime {
+		fn children_of(kitty_id: pallet_kitties::KittyIndex) -> Vec<pallet_kitties::KittyIndex> {
+			KittiesModule::children_of(kitty_id)
+		}
+
+		fn ancestors(kitty_id: pallet_kitties::KittyIndex, depth: u32) -> Vec<pallet_kitties::KittyIndex> {
+			KittiesModule::ancestors(kitty_id, depth)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,